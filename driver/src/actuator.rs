@@ -49,72 +49,52 @@ macro_rules! instruction_method {
 }
 
 macro_rules! control_table_methods {
-    ($id:ident, $bits:expr) => {
+    ($access:ident, $module:ident :: $id:ident) => {
+        paste! { control_table_methods!(@ $access, [< $module _ $id:snake >], ::dxl_packet::control_table::$module::$id); }
+    };
+    ($access:ident, $id:ident) => {
+        paste! { control_table_methods!(@ $access, [< $id:snake >], ::dxl_packet::control_table::$id); }
+    };
+    (@ ReadOnly, $name:ident, $item:ty) => {
         paste! {
             #[inline]
-            pub async fn [< read_ $id:snake >](
+            pub async fn [< read_ $name >](
                 &self,
-            ) -> Result<[< u $bits >], $crate::ActuatorError<C, M>> {
-                defmt::debug!("Reading {}'s {}...", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION);
-                let result = {
-                    let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
-                    lock.[< read_ $id:snake >](self.id).await
-                    // release mutex lock by ending `lock`'s scope
-                };
-                match result {
-                    Ok(::dxl_packet::recv::Read { bytes }) => {
-                        let uint = [< u $bits >]::from_le_bytes(bytes);
-                        defmt::debug!("    --> {}'s {} is {}", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION, uint);
-                        Ok(uint)
-                    }
-                    Err(e) => Err(crate::ActuatorError::Packet(self.complete_bus_error(e).await)),
-                }
+            ) -> Result<<$item as ::dxl_packet::control_table::Item>::Value, $crate::ActuatorError<C, M>> {
+                self.read::<$item>().await
             }
+        }
+    };
+    (@ ReadWrite, $name:ident, $item:ty) => {
+        control_table_methods!(@ ReadOnly, $name, $item);
 
+        paste! {
             #[inline]
-            pub async fn [< write_ $id:snake >](
-                &self, value: [< u $bits >],
+            pub async fn [< write_ $name >](
+                &self, value: <$item as ::dxl_packet::control_table::Item>::Value,
             ) -> Result<(), $crate::ActuatorError<C, M>> {
-                defmt::debug!("Writing {}'s {} to {}...", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION, value);
-                let bytes = value.to_le_bytes();
-                let result = {
-                    let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
-                    lock.[< write_ $id:snake >](self.id, bytes).await
-                    // release mutex lock by ending `lock`'s scope
-                };
-                match result {
-                    Ok(()) => {
-                        defmt::debug!("    --> updated {}'s {} to {}", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION, value);
-                        Ok(())
-                    }
-                    Err(e) => Err(crate::ActuatorError::Packet(self.complete_bus_error(e).await)),
-                }
+                self.write::<$item>(value).await
             }
 
             #[inline]
-            pub async fn [< reg_write_ $id:snake >](
-                &self, value: [< u $bits >],
+            pub async fn [< reg_write_ $name >](
+                &self, value: <$item as ::dxl_packet::control_table::Item>::Value,
             ) -> Result<(), $crate::ActuatorError<C, M>> {
-                defmt::debug!("Register-writing {}'s {} to {}...", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION, value);
-                let bytes = value.to_le_bytes();
-                let result = {
-                    let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
-                    lock.[< reg_write_ $id:snake >](self.id, bytes).await
-                    // release mutex lock by ending `lock`'s scope
-                };
-                match result {
-                    Ok(()) => {
-                        defmt::debug!("    --> registered an update of {}'s {} to {}", self, <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::DESCRIPTION, value);
-                        Ok(())
-                    }
-                    Err(e) => Err(crate::ActuatorError::Packet(self.complete_bus_error(e).await)),
-                }
+                self.reg_write::<$item>(value).await
             }
         }
     };
 }
 
 pub enum InitError<C: Comm, M: Mutex> {
+    Ping {
+        id: u8,
+        error: crate::ActuatorError<C, M>,
+    },
+    Read {
+        id: u8,
+        error: crate::ActuatorError<C, M>,
+    },
     Write {
         id: u8,
         error: crate::ActuatorError<C, M>,
@@ -130,6 +110,18 @@ impl<C: Comm, M: Mutex> defmt::Format for InitError<C, M> {
     #[inline]
     fn format(&self, f: defmt::Formatter) {
         match *self {
+            Self::Ping { id, ref error } => defmt::write!(
+                f,
+                "Error pinging Dynamixel ID {} to identify its model: {}",
+                id,
+                error
+            ),
+            Self::Read { id, ref error } => defmt::write!(
+                f,
+                "Error reading parameters while initializing Dynamixel ID {}: {}",
+                id,
+                error
+            ),
             Self::Write { id, ref error } => defmt::write!(
                 f,
                 "Error setting parameters while initializing Dynamixel ID {}: {}",
@@ -259,6 +251,24 @@ pub enum Position {
     Specific { position: f32, tolerance: f32 },
 }
 
+/// One of the external ports on 540-series (1-3) and Dynamixel-P (1-4) models.
+#[derive(Clone, Copy, defmt::Format)]
+pub enum ExternalPort {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// How long to keep polling `BackupReady` while an actuator writes its backup to EEPROM.
+const BACKUP_WAIT_US: u32 = 1_000_000;
+
+/// `ReturnDelayTime` counts in units of 2 µs.
+#[inline(always)]
+const fn return_delay_us(return_delay_time: u8) -> u16 {
+    2 * return_delay_time as u16
+}
+
 pub struct Config {
     pub description: &'static str,
     pub id: u8,
@@ -270,6 +280,12 @@ pub struct Actuator<'bus, C: Comm, M: Mutex<Item = Bus<C>>> {
     description: &'static str,
     id: u8,
     limits: Option<KnownLimits>,
+    model: &'static ::dxl_packet::control_table::model::Table,
+    torque_off_for_eeprom: bool,
+    /// Scale factors for this model number, if we know them.
+    units: Option<::dxl_packet::control_table::units::Units>,
+    /// Which `Mapping` the indirect addresses point at, if we've checked.
+    indirect: Option<core::any::TypeId>,
 }
 
 impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
@@ -279,11 +295,15 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         id: u8,
         description: &'static str,
     ) -> Result<Self, InitError<C, M>> {
-        let actuator = Self {
+        let mut actuator = Self {
             bus,
             description,
             id,
             limits: None,
+            model: &::dxl_packet::control_table::model::UNRECOGNIZED,
+            torque_off_for_eeprom: false,
+            units: None,
+            indirect: None,
         };
 
         #[cfg(debug_assertions)]
@@ -294,6 +314,16 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             .check_duplicate_id(id)
             .map_err(InitError::Id)?;
 
+        let ::dxl_packet::recv::Ping { model_number, .. } = actuator
+            .ping()
+            .await
+            .map_err(|error| InitError::Ping { id, error })?;
+        actuator.identify(model_number);
+        let () = actuator
+            .learn_response_timing()
+            .await
+            .map_err(|error| InitError::Read { id, error })?;
+
         Ok(actuator)
     }
 
@@ -303,14 +333,27 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         id: u8,
         description: &'static str,
     ) -> Result<Self, InitError<C, M>> {
+        use ::dxl_packet::control_table::{ProfileVelocity, p, xl320};
         let actuator = Self::init_unconfigured(bus, id, description).await?;
+        if actuator.model.has::<xl320::MovingSpeed>() {
+            // No velocity profile, but a moving speed of 0 means as fast as it goes:
+            let () = actuator
+                .write::<xl320::MovingSpeed>(0)
+                .await
+                .map_err(|error| InitError::Write { id, error })?;
+            return Ok(actuator);
+        }
         let mut max = u32::MAX;
         'max_velocity: loop {
-            match actuator.write_profile_velocity(max).await {
+            match actuator
+                .write_x_or_p::<ProfileVelocity, p::ProfileVelocity>(max)
+                .await
+            {
                 Ok(()) => break 'max_velocity,
+                // Halving 1 would reach 0, which means no limit at all:
                 Err(crate::ActuatorError::Packet(crate::actuator::Error::Software(
                     ::dxl_packet::packet::recv::SoftwareError::DataRangeError,
-                ))) => {
+                ))) if max > 1 => {
                     defmt::debug!(
                         "Maximum velocity of `{}` is too much for ID {} (\"{}\"); cutting in half...",
                         max,
@@ -364,7 +407,7 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             } => {
                 let mut actuator = Self::init_with_max_velocity(bus, id, description).await?;
                 let () = actuator
-                    .write_profile_acceleration(1)
+                    .write_acceleration_profile(1)
                     .await
                     .map_err(|error| InitError::Write { id, error })?;
                 defmt::info!("Slowly moving {} to position {}...", actuator, position);
@@ -417,6 +460,125 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         }
     }
 
+    /// Pick the control table for this model number,
+    /// falling back to allowing every item if we don't recognize it.
+    #[inline]
+    fn identify(&mut self, model_number: u16) {
+        self.model = match ::dxl_packet::control_table::model::lookup(model_number) {
+            Some(model) => model,
+            None => {
+                defmt::warn!(
+                    "{} reported unrecognized model number {}; not checking its control table",
+                    self,
+                    model_number,
+                );
+                &::dxl_packet::control_table::model::UNRECOGNIZED
+            }
+        };
+        self.units = ::dxl_packet::control_table::units::of(model_number);
+        defmt::debug!("{} is {} (model number {})", self, self.model, model_number);
+    }
+
+    /// Tell the bus which instructions this actuator answers and how long it waits to answer,
+    /// so it doesn't wait for status packets that won't come or longer than it has to.
+    /// Having just answered a ping, an actuator that doesn't answer a read must be at `PingOnly`.
+    #[inline]
+    async fn learn_response_timing(&self) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{
+            ReturnDelayTime, StatusReturnLevel, ax, p, value, xl320,
+        };
+        let result = if self.model.has::<p::StatusReturnLevel>() {
+            self.read_as_is::<p::StatusReturnLevel>().await
+        } else if self.model.has::<ax::StatusReturnLevel>() {
+            self.read_as_is::<ax::StatusReturnLevel>().await
+        } else if self.model.has::<xl320::StatusReturnLevel>() {
+            self.read_as_is::<xl320::StatusReturnLevel>().await
+        } else {
+            self.read_as_is::<StatusReturnLevel>().await
+        };
+        let level = match result {
+            Ok(level) => level,
+            Err(crate::ActuatorError::Packet(Error::Io(
+                crate::IoError::Recv(_) | crate::IoError::NoStatus { .. },
+            ))) => {
+                defmt::warn!(
+                    "{} answers pings but not reads; assuming it only answers pings",
+                    self
+                );
+                value::StatusReturnLevel::PingOnly
+            }
+            Err(e) => return Err(e),
+        };
+        let () = self
+            .bus
+            .lock()
+            .await
+            .map_err(crate::ActuatorError::Mutex)?
+            .set_status_return_level(self.id, level);
+        if level == value::StatusReturnLevel::PingOnly {
+            // Can't ask, so the bus keeps assuming the longest:
+            return Ok(());
+        }
+        let return_delay_time = if self.model.has::<xl320::ReturnDelayTime>() {
+            self.read_as_is::<xl320::ReturnDelayTime>().await?
+        } else {
+            self.read_as_is::<ReturnDelayTime>().await?
+        };
+        let () = self
+            .bus
+            .lock()
+            .await
+            .map_err(crate::ActuatorError::Mutex)?
+            .set_return_delay_us(self.id, return_delay_us(return_delay_time));
+        Ok(())
+    }
+
+    /// Whether `I` is this model's version of a one-byte item
+    /// that other models keep elsewhere, i.e. at one of `addresses`.
+    #[inline]
+    fn is_one_of<I: ::dxl_packet::control_table::Item>(&self, addresses: &[u16]) -> bool {
+        // Known at compile time, so most items skip the lookup:
+        I::BYTES == 1 && addresses.contains(&I::ADDRESS) && self.model.has::<I>()
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn model(&self) -> &'static ::dxl_packet::control_table::model::Table {
+        self.model
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn units(&self) -> Option<::dxl_packet::control_table::units::Units> {
+        self.units
+    }
+
+    #[inline]
+    fn known_units(
+        &self,
+        quantity: &'static str,
+    ) -> Result<::dxl_packet::control_table::units::Units, crate::ActuatorError<C, M>> {
+        self.units.ok_or(crate::ActuatorError::UnknownUnits {
+            quantity,
+            model: self.model.name,
+        })
+    }
+
+    /// Refuse items this model doesn't have before sending anything.
+    #[inline]
+    fn check<I: ::dxl_packet::control_table::Item>(
+        &self,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        if self.model.has::<I>() {
+            Ok(())
+        } else {
+            Err(crate::ActuatorError::Unsupported {
+                item: I::DESCRIPTION,
+                model: self.model.name,
+            })
+        }
+    }
+
     #[inline]
     async fn complete_bus_error<Output>(
         &self,
@@ -433,96 +595,239 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         &self,
         error_including_hardware: ::dxl_packet::packet::recv::PersistentError<Output>,
     ) -> Error<C> {
-        match error_including_hardware {
-            ::dxl_packet::packet::recv::PersistentError::Software(e) => Error::Software(e),
+        let hardware_error = match error_including_hardware {
+            ::dxl_packet::packet::recv::PersistentError::Software(e) => return Error::Software(e),
+            // Protocol 1.0 already said what it was:
+            ::dxl_packet::packet::recv::PersistentError::HardwareDetailed(_, errors) => {
+                Error::Hardware(errors)
+            }
             ::dxl_packet::packet::recv::PersistentError::Hardware(..) => {
                 defmt::debug!("Hardware error reported for {}; reading it...", self);
-                let hardware_error = {
-                    let result = match self.bus.lock().await {
-                        Ok(mut lock) => lock
-                            .read_hardware_error_status(self.id)
-                            .await
-                            .map_err(crate::BusError::<_, M, _>::Packet),
-                        Err(e) => Err(crate::BusError::Mutex(e)),
-                    };
-                    match result {
-                        Ok(::dxl_packet::recv::Read { bytes: [byte] })
-                        | Err(crate::BusError::Packet(crate::bus::Error::Packet(
-                            ::dxl_packet::packet::recv::PersistentError::Hardware(
-                                ::dxl_packet::recv::Read { bytes: [byte] },
-                            ),
-                        ))) => Error::Hardware(
-                            ::dxl_packet::recv::HardwareErrorStatus::parse_byte(byte),
-                        ),
-                        Err(e) => {
-                            defmt::error!(
-                                "While reading a hardware error for {}, another error occurred: {}",
-                                self,
-                                e
-                            );
-                            Error::HardwareUnknown
-                        }
-                    }
-                };
-                defmt::error!("HARDWARE ERROR FOR {}: {}", self, hardware_error);
-                let reboot_result = match self.bus.lock().await {
-                    Ok(mut lock) => lock
-                        .reboot(self.id)
+                use ::dxl_packet::control_table::{HardwareErrorStatus, p, xl320};
+                if self.model.has::<p::HardwareErrorStatus>() {
+                    self.read_hardware_error::<p::HardwareErrorStatus>().await
+                } else if self.model.has::<xl320::HardwareErrorStatus>() {
+                    self.read_hardware_error::<xl320::HardwareErrorStatus>()
                         .await
-                        .map_err(crate::BusError::<_, M, _>::Packet),
-                    Err(e) => Err(crate::BusError::Mutex(e)),
-                };
-                let () = match reboot_result {
-                    Ok(()) => 'torque_on: loop {
-                        let torque_result = match self.bus.lock().await {
-                            Ok(mut lock) => lock
-                                .write_torque_enable(self.id, [1])
-                                .await
-                                .map_err(crate::BusError::<_, M, _>::Packet),
-                            Err(e) => Err(crate::BusError::Mutex(e)),
-                        };
-                        match torque_result {
-                            Ok(()) => break 'torque_on,
-                            Err(crate::BusError::Mutex(e)) => defmt::debug!(
-                                "Still waiting to enable torque for {}: {}; probably still rebooting",
-                                self,
-                                e
-                            ),
-                            Err(crate::BusError::Packet(crate::bus::Error::Io(e))) => {
-                                defmt::debug!(
-                                    "Still waiting to enable torque for {}: {}; probably still rebooting",
-                                    self,
-                                    e
-                                )
-                            }
-                            Err(e) => defmt::error!("Couldn't enable torque for {}: {}", self, e),
-                        }
-                        let () = C::yield_to_other_tasks().await;
-                    },
-                    Err(e) => defmt::error!("Couldn't reboot {}: {}", self, e),
-                };
-                hardware_error
+                } else {
+                    self.read_hardware_error::<HardwareErrorStatus>().await
+                }
+            }
+        };
+        defmt::error!("HARDWARE ERROR FOR {}: {}", self, hardware_error);
+        let reboot_result = match self.bus.lock().await {
+            Ok(mut lock) => lock
+                .reboot(self.id)
+                .await
+                .map_err(crate::BusError::<_, M, _>::Packet),
+            Err(e) => Err(crate::BusError::Mutex(e)),
+        };
+        let () = match reboot_result {
+            Ok(()) => 'torque_on: loop {
+                match self.bus_torque_on().await {
+                    Ok(()) => break 'torque_on,
+                    Err(crate::BusError::Mutex(e)) => defmt::debug!(
+                        "Still waiting to enable torque for {}: {}; probably still rebooting",
+                        self,
+                        e
+                    ),
+                    Err(crate::BusError::Packet(crate::bus::Error::Io(e))) => {
+                        defmt::debug!(
+                            "Still waiting to enable torque for {}: {}; probably still rebooting",
+                            self,
+                            e
+                        )
+                    }
+                    Err(e) => defmt::error!("Couldn't enable torque for {}: {}", self, e),
+                }
+                let () = C::yield_to_other_tasks().await;
+            },
+            Err(e) => defmt::error!("Couldn't reboot {}: {}", self, e),
+        };
+        hardware_error
+    }
+
+    /// Read `I` (this model's `HardwareErrorStatus`) to find out what a hardware error was.
+    #[inline]
+    async fn read_hardware_error<I: ::dxl_packet::control_table::Item>(&self) -> Error<C>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        let result = match self.bus.lock().await {
+            Ok(mut lock) => lock
+                .read::<I>(self.id)
+                .await
+                .map_err(crate::BusError::<_, M, _>::Packet),
+            Err(e) => Err(crate::BusError::Mutex(e)),
+        };
+        match result {
+            Ok(errors)
+            | Err(crate::BusError::Packet(crate::bus::Error::Packet(
+                ::dxl_packet::packet::recv::PersistentError::Hardware(errors),
+            ))) => match errors.to_le_bytes().first() {
+                Some(&byte) => {
+                    Error::Hardware(::dxl_packet::recv::HardwareErrorStatus::parse_byte(byte))
+                }
+                None => Error::HardwareUnknown,
+            },
+            Err(e) => {
+                defmt::error!(
+                    "While reading a hardware error for {}, another error occurred: {}",
+                    self,
+                    e
+                );
+                Error::HardwareUnknown
             }
         }
     }
 
+    /// Like `set_torque(true)`, but straight through the bus, for `complete_packet_error`
+    /// to use after rebooting without completing errors of its own.
+    #[inline]
+    async fn bus_torque_on(&self) -> Result<(), crate::BusError<C, M, ::dxl_packet::recv::Write>> {
+        use ::dxl_packet::control_table::{TorqueEnable, p, xl320};
+        let mut lock = self.bus.lock().await.map_err(crate::BusError::Mutex)?;
+        let result = if self.model.has::<p::TorqueEnable>() {
+            lock.write::<p::TorqueEnable>(self.id, true).await
+        } else if self.model.has::<xl320::TorqueEnable>() {
+            lock.write::<xl320::TorqueEnable>(self.id, true).await
+        } else {
+            lock.write::<TorqueEnable>(self.id, true).await
+        };
+        result.map_err(crate::BusError::Packet)
+    }
+
     #[inline(always)]
     pub async fn reset_acceleration_profile(&self) -> Result<(), crate::ActuatorError<C, M>> {
-        self.write_profile_acceleration(
-            // Snappy enough without seeming digital:
-            128,
-        )
-        .await
+        // Snappy enough without seeming digital:
+        self.write_acceleration_profile(128).await
+    }
+
+    /// `ProfileAcceleration`, or MX's `GoalAcceleration` (saturating);
+    /// AX and XL-320 models can't limit acceleration, so for them this does nothing.
+    #[inline]
+    async fn write_acceleration_profile(
+        &self,
+        value: u32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{ProfileAcceleration, mx, p, xl320};
+        if self.model.has::<mx::GoalAcceleration>() {
+            // 0 means no limit, and 254 is the most it takes:
+            self.write::<mx::GoalAcceleration>(u8::try_from(value.clamp(1, 254)).unwrap_or(254))
+                .await
+        } else if self.model.has::<xl320::MovingSpeed>() {
+            defmt::debug!("{} has no acceleration profile to set", self);
+            Ok(())
+        } else {
+            self.write_x_or_p::<ProfileAcceleration, p::ProfileAcceleration>(value)
+                .await
+        }
     }
 
     #[inline(always)]
     pub async fn torque_off(&self) -> Result<(), crate::ActuatorError<C, M>> {
-        self.write_torque_enable(0).await
+        self.set_torque(false).await
     }
 
     #[inline(always)]
     pub async fn torque_on(&self) -> Result<(), crate::ActuatorError<C, M>> {
-        self.write_torque_enable(1).await
+        self.set_torque(true).await
+    }
+
+    /// `TorqueEnable` lives at a different address on some models
+    /// (AX and MX keep it where the XL-320 does).
+    #[inline]
+    async fn set_torque(&self, enabled: bool) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{TorqueEnable, p, xl320};
+        if self.model.has::<p::TorqueEnable>() {
+            self.write_as_is::<p::TorqueEnable>(enabled).await
+        } else if self.model.has::<xl320::TorqueEnable>() {
+            self.write_as_is::<xl320::TorqueEnable>(enabled).await
+        } else {
+            self.write_as_is::<TorqueEnable>(enabled).await
+        }
+    }
+
+    #[inline]
+    pub async fn torque_enabled(&self) -> Result<bool, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{TorqueEnable, p, xl320};
+        if self.model.has::<p::TorqueEnable>() {
+            self.read::<p::TorqueEnable>().await
+        } else if self.model.has::<xl320::TorqueEnable>() {
+            self.read::<xl320::TorqueEnable>().await
+        } else {
+            self.read::<TorqueEnable>().await
+        }
+    }
+
+    /// Set what an external port does (e.g. read a limit switch with `DigitalInputPullUp`).
+    /// The mode is in EEPROM, so see `set_torque_off_for_eeprom`.
+    #[inline]
+    pub async fn configure_external_port(
+        &self,
+        port: ExternalPort,
+        mode: ::dxl_packet::control_table::value::ExternalPortMode,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{
+            ExternalPortMode1, ExternalPortMode2, ExternalPortMode3, p,
+        };
+        match port {
+            ExternalPort::One => self.write::<ExternalPortMode1>(mode).await,
+            ExternalPort::Two => self.write::<ExternalPortMode2>(mode).await,
+            ExternalPort::Three => self.write::<ExternalPortMode3>(mode).await,
+            ExternalPort::Four => self.write::<p::ExternalPortMode4>(mode).await,
+        }
+    }
+
+    /// An analog input (0-4095), or 0 or 1 for a digital input or output.
+    /// External port data lives at a different address on some models.
+    #[inline]
+    pub async fn read_external_port(
+        &self,
+        port: ExternalPort,
+    ) -> Result<u16, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{
+            ExternalPortData1, ExternalPortData2, ExternalPortData3, p,
+        };
+        match (port, self.model.has::<p::ExternalPortData1>()) {
+            (ExternalPort::One, true) => self.read::<p::ExternalPortData1>().await,
+            (ExternalPort::One, false) => self.read::<ExternalPortData1>().await,
+            (ExternalPort::Two, true) => self.read::<p::ExternalPortData2>().await,
+            (ExternalPort::Two, false) => self.read::<ExternalPortData2>().await,
+            (ExternalPort::Three, true) => self.read::<p::ExternalPortData3>().await,
+            (ExternalPort::Three, false) => self.read::<ExternalPortData3>().await,
+            (ExternalPort::Four, _) => self.read::<p::ExternalPortData4>().await,
+        }
+    }
+
+    /// Drive a port configured as `DigitalOutput` low (0) or high (1).
+    #[inline]
+    pub async fn write_external_port(
+        &self,
+        port: ExternalPort,
+        value: u16,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{
+            ExternalPortData1, ExternalPortData2, ExternalPortData3, p,
+        };
+        match (port, self.model.has::<p::ExternalPortData1>()) {
+            (ExternalPort::One, true) => self.write::<p::ExternalPortData1>(value).await,
+            (ExternalPort::One, false) => self.write::<ExternalPortData1>(value).await,
+            (ExternalPort::Two, true) => self.write::<p::ExternalPortData2>(value).await,
+            (ExternalPort::Two, false) => self.write::<ExternalPortData2>(value).await,
+            (ExternalPort::Three, true) => self.write::<p::ExternalPortData3>(value).await,
+            (ExternalPort::Three, false) => self.write::<ExternalPortData3>(value).await,
+            (ExternalPort::Four, _) => self.write::<p::ExternalPortData4>(value).await,
+        }
+    }
+
+    /// Whether `write` should turn torque off before writing EEPROM
+    /// (which the actuator refuses with torque on) and back on afterward.
+    /// Off by default, since dropping torque can drop whatever the actuator is holding.
+    #[inline(always)]
+    pub const fn set_torque_off_for_eeprom(&mut self, enabled: bool) {
+        self.torque_off_for_eeprom = enabled;
     }
 
     #[inline]
@@ -531,8 +836,19 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         Ok(match self.limits {
             Some(ref known) => known,
             None => self.limits.insert({
-                let max: u32 = self.read_max_position_limit().await?;
-                let min: u32 = self.read_min_position_limit().await?;
+                use ::dxl_packet::control_table::xl320;
+                // AX, MX, and XL-320 models call them angle limits (counterclockwise being the max):
+                let (max, min): (u32, u32) = if self.model.has::<xl320::CcwAngleLimit>() {
+                    (
+                        self.read::<xl320::CcwAngleLimit>().await?.into(),
+                        self.read::<xl320::CwAngleLimit>().await?.into(),
+                    )
+                } else {
+                    (
+                        self.read_max_position_limit().await?,
+                        self.read_min_position_limit().await?,
+                    )
+                };
                 defmt::info!("Position limits for {}: [{}..{}]", self, min, max);
                 KnownLimits {
                     min: min as f32,
@@ -542,11 +858,101 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         })
     }
 
+    /// Reset the multi-turn revolution count (the actuator must not be moving),
+    /// then re-read the position limits, since the cached ones may no longer apply.
+    #[inline]
+    pub async fn clear_multi_turn(&mut self) -> Result<(), crate::ActuatorError<C, M>> {
+        defmt::debug!(
+            "{} {}...",
+            <::dxl_packet::send::Clear as ::dxl_packet::Instruction>::GERUND,
+            self
+        );
+        let result = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            lock.clear_multi_turn(self.id).await
+            // release mutex lock by ending `lock`'s scope
+        };
+        if let Err(e) = result {
+            return Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            ));
+        }
+        self.limits = None;
+        let _: &KnownLimits = self.limits().await?;
+        Ok(())
+    }
+
+    #[inline]
+    async fn control_table_backup(
+        &self,
+        operation: ::dxl_packet::send::Backup,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        defmt::debug!("Control table backup ({}) for {}...", operation, self);
+        let (result, window_us) = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            (
+                lock.control_table_backup(self.id, operation).await,
+                lock.read_window_us::<::dxl_packet::control_table::BackupReady>(self.id),
+            )
+            // release mutex lock by ending `lock`'s scope
+        };
+        if let Err(e) = result {
+            return Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            ));
+        }
+        // The actuator may stay silent while it writes to EEPROM,
+        // in which case each attempt takes about one response window:
+        let max_attempts = BACKUP_WAIT_US.div_ceil(window_us);
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match self.read_backup_ready().await {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(crate::ActuatorError::Packet(ref e @ Error::Io(crate::IoError::Recv(_)))) => {
+                    defmt::debug!("Still waiting for {}'s control table backup: {}", self, e)
+                }
+                Err(e) => return Err(e),
+            }
+            if attempts >= max_attempts {
+                return Err(crate::ActuatorError::BackupTimeout { operation });
+            }
+            let () = C::yield_to_other_tasks().await;
+        }
+        defmt::debug!(
+            "    --> control table backup ({}) for {} is ready",
+            operation,
+            self
+        );
+        Ok(())
+    }
+
+    /// Checkpoint the current control table (with torque off)
+    /// into the actuator's backup area, waiting until `BackupReady` confirms it.
+    #[inline(always)]
+    pub async fn backup_control_table(&self) -> Result<(), crate::ActuatorError<C, M>> {
+        self.control_table_backup(::dxl_packet::send::Backup::Store)
+            .await
+    }
+
+    /// Restore the control table (with torque off) from the last checkpoint,
+    /// then forget any cached position limits, which may have changed.
+    #[inline]
+    pub async fn restore_control_table(&mut self) -> Result<(), crate::ActuatorError<C, M>> {
+        let () = self
+            .control_table_backup(::dxl_packet::send::Backup::Restore)
+            .await?;
+        self.limits = None;
+        self.indirect = None;
+        Ok(())
+    }
+
     #[inline]
     async fn make_position_absolute(
         &mut self,
         relative: f32,
-    ) -> Result<u32, RelativePositionError<C, M>> {
+    ) -> Result<i32, RelativePositionError<C, M>> {
         if relative < 0. {
             return Err(RelativePositionError::LessThanZero {
                 id: self.id,
@@ -565,13 +971,13 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             .await
             .map_err(|error| RelativePositionError::Limits { id, error })?;
         let absolute_position = min + (range * relative);
-        Ok(absolute_position as u32)
+        Ok(absolute_position as i32)
     }
 
     #[inline]
     async fn make_position_relative(
         &mut self,
-        absolute: u32,
+        absolute: i32,
     ) -> Result<f32, RelativePositionError<C, M>> {
         let id = self.id;
         let KnownLimits { min, range } = self
@@ -587,7 +993,7 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             .make_position_absolute(position)
             .await
             .map_err(GoToError::RelativePosition)?;
-        self.write_goal_position(absolute_position)
+        self.write_goal_position_ticks(absolute_position)
             .await
             .map_err(|error| GoToError::Write { id: self.id, error })
     }
@@ -603,7 +1009,7 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             .await
             .map_err(FollowToError::RelativePosition)?;
         let () = self
-            .write_goal_position(absolute_position)
+            .write_goal_position_ticks(absolute_position)
             .await
             .map_err(|error| FollowToError::Write { id: self.id, error })?;
         loop {
@@ -620,10 +1026,282 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
         }
     }
 
+    #[inline]
+    pub async fn read<I: ::dxl_packet::control_table::Item>(
+        &self,
+    ) -> Result<I::Value, crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        let () = self.check::<I>()?;
+        self.read_as_is::<I>().await
+    }
+
+    #[inline]
+    async fn read_as_is<I: ::dxl_packet::control_table::Item>(
+        &self,
+    ) -> Result<I::Value, crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        defmt::debug!("Reading {}'s {}...", self, I::DESCRIPTION);
+        let result = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            lock.read::<I>(self.id).await
+            // release mutex lock by ending `lock`'s scope
+        };
+        match result {
+            Ok(value) => {
+                defmt::debug!("    --> {}'s {} is {}", self, I::DESCRIPTION, value);
+                Ok(value)
+            }
+            Err(e) => Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            )),
+        }
+    }
+
+    /// Write an item, first turning torque off if it's in EEPROM
+    /// and `set_torque_off_for_eeprom(true)` was called
+    /// (then turning it back on, even if the write failed).
+    #[inline]
+    pub async fn write<I: ::dxl_packet::control_table::Writable>(
+        &self,
+        value: I::Value,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        let () = self.check::<I>()?;
+        if self.torque_off_for_eeprom
+            && matches!(I::AREA, ::dxl_packet::control_table::Area::Eeprom)
+        {
+            self.write_with_torque_off::<I>(value).await
+        } else {
+            self.write_as_is::<I>(value).await
+        }
+    }
+
+    /// Turn torque off (if it's on), write, then turn it back on, even if the write failed.
+    #[inline]
+    async fn write_with_torque_off<I: ::dxl_packet::control_table::Writable>(
+        &self,
+        value: I::Value,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        if !self.torque_enabled().await? {
+            return self.write_as_is::<I>(value).await;
+        }
+        defmt::debug!(
+            "Turning torque off to write {}'s {}...",
+            self,
+            I::DESCRIPTION
+        );
+        let () = self.set_torque(false).await?;
+        let result = self.write_as_is::<I>(value).await;
+        // The write's error comes first, since it's what the caller asked about:
+        match (result, self.set_torque(true).await) {
+            (result, Ok(())) => result,
+            (Ok(()), Err(e)) => Err(e),
+            (
+                Err(crate::ActuatorError::Packet(write)),
+                Err(crate::ActuatorError::Packet(torque)),
+            ) => Err(crate::ActuatorError::TorqueLeftOff { write, torque }),
+            (Err(e), Err(torque)) => {
+                defmt::error!("Couldn't turn {}'s torque back on: {}", self, torque);
+                Err(e)
+            }
+        }
+    }
+
+    #[inline]
+    async fn write_as_is<I: ::dxl_packet::control_table::Writable>(
+        &self,
+        value: I::Value,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        defmt::debug!("Writing {}'s {} to {}...", self, I::DESCRIPTION, value);
+        let result = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            // Keep the bus in step with how this actuator answers,
+            // since the new value already applies to this write's response:
+            if let Some(&byte) =
+                ::dxl_packet::control_table::value::Value::to_le_bytes(value).first()
+            {
+                use ::dxl_packet::control_table::{
+                    Item, ReturnDelayTime, StatusReturnLevel, ax, p, value::Value, xl320,
+                };
+                if self.is_one_of::<I>(&[
+                    StatusReturnLevel::ADDRESS,
+                    p::StatusReturnLevel::ADDRESS,
+                    xl320::StatusReturnLevel::ADDRESS,
+                    ax::StatusReturnLevel::ADDRESS,
+                ]) {
+                    lock.set_status_return_level(
+                        self.id,
+                        ::dxl_packet::control_table::value::StatusReturnLevel::from_le_bytes([
+                            byte,
+                        ]),
+                    );
+                } else if self
+                    .is_one_of::<I>(&[ReturnDelayTime::ADDRESS, xl320::ReturnDelayTime::ADDRESS])
+                {
+                    lock.set_return_delay_us(self.id, return_delay_us(byte));
+                }
+            }
+            lock.write::<I>(self.id, value).await
+            // release mutex lock by ending `lock`'s scope
+        };
+        match result {
+            Ok(()) => {
+                defmt::debug!("    --> updated {}'s {} to {}", self, I::DESCRIPTION, value);
+                Ok(())
+            }
+            Err(e) => Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            )),
+        }
+    }
+
+    #[inline]
+    pub async fn reg_write<I: ::dxl_packet::control_table::Writable>(
+        &self,
+        value: I::Value,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+    {
+        let () = self.check::<I>()?;
+        defmt::debug!(
+            "Register-writing {}'s {} to {}...",
+            self,
+            I::DESCRIPTION,
+            value
+        );
+        let result = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            lock.reg_write::<I>(self.id, value).await
+            // release mutex lock by ending `lock`'s scope
+        };
+        match result {
+            Ok(()) => {
+                defmt::debug!(
+                    "    --> registered an update of {}'s {} to {}",
+                    self,
+                    I::DESCRIPTION,
+                    value
+                );
+                Ok(())
+            }
+            Err(e) => Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            )),
+        }
+    }
+
+    /// Read a run of contiguous items in one round trip.
+    #[inline(always)]
+    pub async fn read_snapshot<S: ::dxl_packet::control_table::Snapshot>(
+        &self,
+    ) -> Result<S, crate::ActuatorError<C, M>>
+    where
+        [(); S::BYTES as usize]:,
+    {
+        self.read::<S>().await
+    }
+
+    /// PWM, current, velocity, position, trajectories, voltage, and temperature in one read.
+    #[inline(always)]
+    pub async fn read_state(
+        &self,
+    ) -> Result<::dxl_packet::control_table::ActuatorState, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::p;
+        if self.model.has::<p::ActuatorState>() {
+            Ok(self.read_snapshot::<p::ActuatorState>().await?.into())
+        } else {
+            self.read_snapshot().await
+        }
+    }
+
+    /// Refuse a mapping this model has nowhere to put, or whose items it doesn't have.
+    #[inline]
+    fn check_indirect<I: ::dxl_packet::control_table::indirect::Mapping>(
+        &self,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        if self.model.indirect != Some(I::REGION) {
+            return Err(crate::ActuatorError::Unsupported {
+                item: I::DESCRIPTION,
+                model: self.model.name,
+            });
+        }
+        match I::ITEMS
+            .iter()
+            .find(|entry| !self.model.covers(entry.address, entry.bytes))
+        {
+            Some(entry) => Err(crate::ActuatorError::Unsupported {
+                item: entry.description,
+                model: self.model.name,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the indirect addresses are known to point at `I`'s items.
+    #[inline]
+    #[must_use]
+    pub fn is_mapped<I: ::dxl_packet::control_table::indirect::Mapping>(&self) -> bool {
+        self.indirect == Some(core::any::TypeId::of::<I>())
+    }
+
+    /// Point the indirect addresses at `I`'s items, unless they already do.
+    /// They live in EEPROM, so if they need rewriting, torque goes off for the write
+    /// (and back on afterward, if it was on), regardless of `set_torque_off_for_eeprom`.
+    #[inline]
+    pub async fn map_indirect<I: ::dxl_packet::control_table::indirect::Mapping>(
+        &mut self,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); <I::Addresses as ::dxl_packet::control_table::Item>::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::{indirect, value::Value};
+        if self.is_mapped::<I>() {
+            return Ok(());
+        }
+        let () = self.check_indirect::<I>()?;
+        let expected = indirect::addresses::<I>();
+        // Mappings survive power cycles, so don't wear out EEPROM rewriting the same one:
+        let actual = self.read_as_is::<I::Addresses>().await?;
+        if actual.to_le_bytes() == expected.to_le_bytes() {
+            defmt::debug!("{} is already mapped to {}", self, I::DESCRIPTION);
+        } else {
+            defmt::debug!("Mapping {} to {}...", self, I::DESCRIPTION);
+            let () = self.write_with_torque_off::<I::Addresses>(expected).await?;
+        }
+        self.indirect = Some(core::any::TypeId::of::<I>());
+        Ok(())
+    }
+
+    /// Read `I`'s items in one round trip, first mapping them (see `map_indirect`) if necessary.
+    #[inline]
+    pub async fn read_indirect<I: ::dxl_packet::control_table::indirect::Mapping>(
+        &mut self,
+    ) -> Result<I::Value, crate::ActuatorError<C, M>>
+    where
+        [(); I::BYTES as usize]:,
+        [(); <I::Addresses as ::dxl_packet::control_table::Item>::BYTES as usize]:,
+    {
+        let () = self.map_indirect::<I>().await?;
+        self.read_as_is::<I>().await
+    }
+
     #[inline(always)]
     pub async fn pos(&mut self) -> Result<f32, PosError<C, M>> {
         let absolute = self
-            .read_present_position()
+            .read_present_position_ticks()
             .await
             .map_err(|error| PosError::Read { id: self.id, error })?;
         self.make_position_relative(absolute)
@@ -631,65 +1309,394 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Actuator<'bus, C, M> {
             .map_err(PosError::RelativePosition)
     }
 
+    /// `PresentPosition` wherever this model keeps it, as a signed X-series position.
+    #[inline]
+    async fn read_present_position_ticks(&self) -> Result<i32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentPosition, ax, p, xl320};
+        if self.model.has::<ax::PresentPosition>() {
+            Ok(self.read::<ax::PresentPosition>().await?.into())
+        } else if self.model.has::<xl320::PresentPosition>() {
+            Ok(self.read::<xl320::PresentPosition>().await?.into())
+        } else {
+            self.read_x_or_p::<PresentPosition, p::PresentPosition>()
+                .await
+        }
+    }
+
+    /// `GoalPosition` wherever this model keeps it, from a signed X-series position
+    /// (saturating on AX, MX, and XL-320 models, whose positions are unsigned and narrower).
+    #[inline]
+    async fn write_goal_position_ticks(
+        &self,
+        position: i32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{GoalPosition, p, xl320};
+        if self.model.has::<xl320::GoalPosition>() {
+            self.write::<xl320::GoalPosition>(u16::try_from(position.max(0)).unwrap_or(u16::MAX))
+                .await
+        } else {
+            self.write_x_or_p::<GoalPosition, p::GoalPosition>(position)
+                .await
+        }
+    }
+
+    /// Read whichever of `X` (the X-series address) or `P` (the Dynamixel-P address) this model has.
+    #[inline]
+    async fn read_x_or_p<
+        X: ::dxl_packet::control_table::Item,
+        P: ::dxl_packet::control_table::Item<Value = X::Value>,
+    >(
+        &self,
+    ) -> Result<X::Value, crate::ActuatorError<C, M>>
+    where
+        [(); X::BYTES as usize]:,
+        [(); P::BYTES as usize]:,
+    {
+        if self.model.has::<P>() {
+            self.read::<P>().await
+        } else {
+            self.read::<X>().await
+        }
+    }
+
+    /// Write whichever of `X` (the X-series address) or `P` (the Dynamixel-P address) this model has.
+    #[inline]
+    async fn write_x_or_p<
+        X: ::dxl_packet::control_table::Writable,
+        P: ::dxl_packet::control_table::Writable<Value = X::Value>,
+    >(
+        &self,
+        value: X::Value,
+    ) -> Result<(), crate::ActuatorError<C, M>>
+    where
+        [(); X::BYTES as usize]:,
+        [(); P::BYTES as usize]:,
+    {
+        if self.model.has::<P>() {
+            self.write::<P>(value).await
+        } else {
+            self.write::<X>(value).await
+        }
+    }
+
+    #[inline]
+    pub async fn read_present_position_rad(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        let units = self.known_units("position")?;
+        let ticks = self.read_present_position_ticks().await?;
+        Ok(units.ticks_to_rad(ticks))
+    }
+
+    #[inline]
+    pub async fn read_present_position_deg(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        let units = self.known_units("position")?;
+        let ticks = self.read_present_position_ticks().await?;
+        Ok(units.ticks_to_deg(ticks))
+    }
+
+    #[inline]
+    pub async fn write_goal_position_rad(
+        &self,
+        rad: f32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        let units = self.known_units("position")?;
+        self.write_goal_position_ticks(units.rad_to_ticks(rad))
+            .await
+    }
+
+    #[inline]
+    pub async fn write_goal_position_deg(
+        &self,
+        deg: f32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        let units = self.known_units("position")?;
+        self.write_goal_position_ticks(units.deg_to_ticks(deg))
+            .await
+    }
+
+    #[inline]
+    pub async fn read_present_velocity_rad_s(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentVelocity, p};
+        let units = self.known_units("velocity")?;
+        let velocity = self
+            .read_x_or_p::<PresentVelocity, p::PresentVelocity>()
+            .await?;
+        Ok(units.velocity_to_rad_s(velocity))
+    }
+
+    #[inline]
+    pub async fn write_goal_velocity_rad_s(
+        &self,
+        rad_s: f32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{GoalVelocity, p};
+        let units = self.known_units("velocity")?;
+        self.write_x_or_p::<GoalVelocity, p::GoalVelocity>(units.rad_s_to_velocity(rad_s))
+            .await
+    }
+
+    #[inline]
+    pub async fn read_present_current_ma(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentCurrent, p};
+        let units = self.known_units("current")?;
+        let current = self
+            .read_x_or_p::<PresentCurrent, p::PresentCurrent>()
+            .await?;
+        units
+            .current_to_ma(current)
+            .ok_or(crate::ActuatorError::UnknownUnits {
+                quantity: "current",
+                model: self.model.name,
+            })
+    }
+
+    #[inline]
+    pub async fn write_goal_current_ma(&self, ma: f32) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{GoalCurrent, p};
+        let Some(current) = self.known_units("current")?.ma_to_current(ma) else {
+            return Err(crate::ActuatorError::UnknownUnits {
+                quantity: "current",
+                model: self.model.name,
+            });
+        };
+        self.write_x_or_p::<GoalCurrent, p::GoalCurrent>(current)
+            .await
+    }
+
+    #[inline]
+    pub async fn read_present_pwm_percent(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentPwm, p};
+        let units = self.known_units("PWM")?;
+        let pwm = self.read_x_or_p::<PresentPwm, p::PresentPwm>().await?;
+        Ok(units.pwm_to_percent(pwm))
+    }
+
+    #[inline]
+    pub async fn write_goal_pwm_percent(
+        &self,
+        percent: f32,
+    ) -> Result<(), crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{GoalPwm, p};
+        let units = self.known_units("PWM")?;
+        self.write_x_or_p::<GoalPwm, p::GoalPwm>(units.percent_to_pwm(percent))
+            .await
+    }
+
+    #[inline]
+    pub async fn read_present_input_voltage_v(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentInputVoltage, p, units};
+        let voltage = self
+            .read_x_or_p::<PresentInputVoltage, p::PresentInputVoltage>()
+            .await?;
+        Ok(units::voltage_to_v(voltage))
+    }
+
+    #[inline]
+    pub async fn read_present_temperature_c(&self) -> Result<f32, crate::ActuatorError<C, M>> {
+        use ::dxl_packet::control_table::{PresentTemperature, p, units};
+        let temperature = self
+            .read_x_or_p::<PresentTemperature, p::PresentTemperature>()
+            .await?;
+        Ok(units::temperature_to_c(temperature))
+    }
+
+    /// Reset the control table to factory defaults and make sure the actuator responds again.
+    /// Note that `Reset::All` moves it to ID 1 at 57,600 baud,
+    /// after which this handle will no longer reach it.
+    #[inline]
+    pub async fn factory_reset(
+        &mut self,
+        scope: ::dxl_packet::send::Reset,
+    ) -> Result<::dxl_packet::recv::Ping, crate::ActuatorError<C, M>> {
+        defmt::debug!(
+            "{} {} ({})...",
+            <::dxl_packet::send::FactoryReset as ::dxl_packet::Instruction>::GERUND,
+            self,
+            scope
+        );
+        let (result, still_here) = {
+            let mut lock = self.bus.lock().await.map_err(crate::ActuatorError::Mutex)?;
+            let still_here =
+                scope.id_after(self.id) == self.id && scope.baud_after(lock.baud()) == lock.baud();
+            (lock.factory_reset(self.id, scope).await, still_here)
+            // release mutex lock by ending `lock`'s scope
+        };
+        // Position limits and indirect addresses are part of the control table:
+        self.limits = None;
+        self.indirect = None;
+        match result {
+            Ok(ok) => {
+                // So is the status return level and return delay, which the bus now assumes are the defaults:
+                if still_here {
+                    let () = self.learn_response_timing().await?;
+                }
+                Ok(ok)
+            }
+            Err(crate::bus::FactoryResetError::Reset(e)) => Err(crate::ActuatorError::Packet(
+                self.complete_bus_error(e).await,
+            )),
+            Err(crate::bus::FactoryResetError::Ping { id, baud, error }) => {
+                defmt::error!(
+                    "{} did not respond as ID {} at {} baud after a factory reset",
+                    self,
+                    id,
+                    baud
+                );
+                Err(crate::ActuatorError::Packet(match error {
+                    crate::bus::Error::Io(e) => Error::Io(e),
+                    crate::bus::Error::Packet(e) if id == self.id => {
+                        self.complete_packet_error(e).await
+                    }
+                    // Can't ask about a hardware error at an ID this handle doesn't own:
+                    crate::bus::Error::Packet(
+                        ::dxl_packet::packet::recv::PersistentError::Software(e),
+                    ) => Error::Software(e),
+                    crate::bus::Error::Packet(
+                        ::dxl_packet::packet::recv::PersistentError::Hardware(_),
+                    ) => Error::HardwareUnknown,
+                    crate::bus::Error::Packet(
+                        ::dxl_packet::packet::recv::PersistentError::HardwareDetailed(_, errors),
+                    ) => Error::Hardware(errors),
+                }))
+            }
+        }
+    }
+
     instruction_method!(ping);
     instruction_method!(action);
-    instruction_method!(factory_reset);
     instruction_method!(reboot);
 
-    control_table_methods!(ModelNumber, 16);
-    control_table_methods!(ModelInformation, 32);
-    control_table_methods!(FirmwareVersion, 8);
-    control_table_methods!(Id, 8);
-    control_table_methods!(BaudRate, 8);
-    control_table_methods!(ReturnDelayTime, 8);
-    control_table_methods!(DriveMode, 8);
-    control_table_methods!(OperatingMode, 8);
-    control_table_methods!(SecondaryId, 8);
-    control_table_methods!(ProtocolType, 8);
-    control_table_methods!(HomingOffset, 32);
-    control_table_methods!(MovingThreshold, 32);
-    control_table_methods!(TemperatureLimit, 8);
-    control_table_methods!(MaxVoltageLimit, 16);
-    control_table_methods!(MinVoltageLimit, 16);
-    control_table_methods!(PwmLimit, 16);
-    control_table_methods!(CurrentLimit, 16);
-    control_table_methods!(VelocityLimit, 32);
-    control_table_methods!(MaxPositionLimit, 32);
-    control_table_methods!(MinPositionLimit, 32);
-    control_table_methods!(StartupConfiguration, 8);
-    control_table_methods!(PwmSlope, 8);
-    control_table_methods!(Shutdown, 8);
-    control_table_methods!(TorqueEnable, 8);
-    control_table_methods!(Led, 8);
-    control_table_methods!(StatusReturnLevel, 8);
-    control_table_methods!(RegisteredInstruction, 8);
-    control_table_methods!(HardwareErrorStatus, 8);
-    control_table_methods!(VelocityIGain, 16);
-    control_table_methods!(VelocityPGain, 16);
-    control_table_methods!(PositionDGain, 16);
-    control_table_methods!(PositionIGain, 16);
-    control_table_methods!(PositionPGain, 16);
-    control_table_methods!(Feedforward2ndGain, 16);
-    control_table_methods!(Feedforward1stGain, 16);
-    control_table_methods!(BusWatchdog, 8);
-    control_table_methods!(GoalPwm, 16);
-    control_table_methods!(GoalCurrent, 16);
-    control_table_methods!(GoalVelocity, 32);
-    control_table_methods!(ProfileAcceleration, 32);
-    control_table_methods!(ProfileVelocity, 32);
-    control_table_methods!(GoalPosition, 32);
-    control_table_methods!(RealtimeTick, 16);
-    control_table_methods!(Moving, 8);
-    control_table_methods!(MovingStatus, 8);
-    control_table_methods!(PresentPwm, 16);
-    control_table_methods!(PresentCurrent, 16);
-    control_table_methods!(PresentVelocity, 32);
-    control_table_methods!(PresentPosition, 32);
-    control_table_methods!(VelocityTrajectory, 32);
-    control_table_methods!(PositionTrajectory, 32);
-    control_table_methods!(PresentInputVoltage, 16);
-    control_table_methods!(PresentTemperature, 8);
-    control_table_methods!(BackupReady, 8);
+    control_table_methods!(ReadOnly, ModelNumber);
+    control_table_methods!(ReadOnly, ModelInformation);
+    control_table_methods!(ReadOnly, FirmwareVersion);
+    control_table_methods!(ReadWrite, Id);
+    control_table_methods!(ReadWrite, BaudRate);
+    control_table_methods!(ReadWrite, ReturnDelayTime);
+    control_table_methods!(ReadWrite, DriveMode);
+    control_table_methods!(ReadWrite, OperatingMode);
+    control_table_methods!(ReadWrite, SecondaryId);
+    control_table_methods!(ReadWrite, ProtocolType);
+    control_table_methods!(ReadWrite, HomingOffset);
+    control_table_methods!(ReadWrite, MovingThreshold);
+    control_table_methods!(ReadWrite, TemperatureLimit);
+    control_table_methods!(ReadWrite, MaxVoltageLimit);
+    control_table_methods!(ReadWrite, MinVoltageLimit);
+    control_table_methods!(ReadWrite, PwmLimit);
+    control_table_methods!(ReadWrite, CurrentLimit);
+    control_table_methods!(ReadWrite, VelocityLimit);
+    control_table_methods!(ReadWrite, MaxPositionLimit);
+    control_table_methods!(ReadWrite, MinPositionLimit);
+    control_table_methods!(ReadWrite, ExternalPortMode1);
+    control_table_methods!(ReadWrite, ExternalPortMode2);
+    control_table_methods!(ReadWrite, ExternalPortMode3);
+    control_table_methods!(ReadWrite, StartupConfiguration);
+    control_table_methods!(ReadWrite, PwmSlope);
+    control_table_methods!(ReadWrite, Shutdown);
+    control_table_methods!(ReadWrite, TorqueEnable);
+    control_table_methods!(ReadWrite, Led);
+    control_table_methods!(ReadWrite, StatusReturnLevel);
+    control_table_methods!(ReadOnly, RegisteredInstruction);
+    control_table_methods!(ReadOnly, HardwareErrorStatus);
+    control_table_methods!(ReadWrite, VelocityIGain);
+    control_table_methods!(ReadWrite, VelocityPGain);
+    control_table_methods!(ReadWrite, PositionDGain);
+    control_table_methods!(ReadWrite, PositionIGain);
+    control_table_methods!(ReadWrite, PositionPGain);
+    control_table_methods!(ReadWrite, Feedforward2ndGain);
+    control_table_methods!(ReadWrite, Feedforward1stGain);
+    control_table_methods!(ReadWrite, BusWatchdog);
+    control_table_methods!(ReadWrite, GoalPwm);
+    control_table_methods!(ReadWrite, GoalCurrent);
+    control_table_methods!(ReadWrite, GoalVelocity);
+    control_table_methods!(ReadWrite, ProfileAcceleration);
+    control_table_methods!(ReadWrite, ProfileVelocity);
+    control_table_methods!(ReadWrite, GoalPosition);
+    control_table_methods!(ReadOnly, RealtimeTick);
+    control_table_methods!(ReadOnly, Moving);
+    control_table_methods!(ReadOnly, MovingStatus);
+    control_table_methods!(ReadOnly, PresentPwm);
+    control_table_methods!(ReadOnly, PresentCurrent);
+    control_table_methods!(ReadOnly, PresentVelocity);
+    control_table_methods!(ReadOnly, PresentPosition);
+    control_table_methods!(ReadOnly, VelocityTrajectory);
+    control_table_methods!(ReadOnly, PositionTrajectory);
+    control_table_methods!(ReadOnly, PresentInputVoltage);
+    control_table_methods!(ReadOnly, PresentTemperature);
+    control_table_methods!(ReadOnly, BackupReady);
+    control_table_methods!(ReadWrite, ExternalPortData1);
+    control_table_methods!(ReadWrite, ExternalPortData2);
+    control_table_methods!(ReadWrite, ExternalPortData3);
+
+    control_table_methods!(ReadWrite, p::AccelerationLimit);
+    control_table_methods!(ReadWrite, p::ExternalPortMode4);
+    control_table_methods!(ReadWrite, p::TorqueEnable);
+    control_table_methods!(ReadWrite, p::LedRed);
+    control_table_methods!(ReadWrite, p::LedGreen);
+    control_table_methods!(ReadWrite, p::LedBlue);
+    control_table_methods!(ReadWrite, p::StatusReturnLevel);
+    control_table_methods!(ReadOnly, p::RegisteredInstruction);
+    control_table_methods!(ReadOnly, p::HardwareErrorStatus);
+    control_table_methods!(ReadWrite, p::VelocityIGain);
+    control_table_methods!(ReadWrite, p::VelocityPGain);
+    control_table_methods!(ReadWrite, p::PositionDGain);
+    control_table_methods!(ReadWrite, p::PositionIGain);
+    control_table_methods!(ReadWrite, p::PositionPGain);
+    control_table_methods!(ReadWrite, p::Feedforward2ndGain);
+    control_table_methods!(ReadWrite, p::Feedforward1stGain);
+    control_table_methods!(ReadWrite, p::BusWatchdog);
+    control_table_methods!(ReadWrite, p::GoalPwm);
+    control_table_methods!(ReadWrite, p::GoalCurrent);
+    control_table_methods!(ReadWrite, p::GoalVelocity);
+    control_table_methods!(ReadWrite, p::ProfileAcceleration);
+    control_table_methods!(ReadWrite, p::ProfileVelocity);
+    control_table_methods!(ReadWrite, p::GoalPosition);
+    control_table_methods!(ReadOnly, p::RealtimeTick);
+    control_table_methods!(ReadOnly, p::Moving);
+    control_table_methods!(ReadOnly, p::MovingStatus);
+    control_table_methods!(ReadOnly, p::PresentPwm);
+    control_table_methods!(ReadOnly, p::PresentCurrent);
+    control_table_methods!(ReadOnly, p::PresentVelocity);
+    control_table_methods!(ReadOnly, p::PresentPosition);
+    control_table_methods!(ReadOnly, p::VelocityTrajectory);
+    control_table_methods!(ReadOnly, p::PositionTrajectory);
+    control_table_methods!(ReadOnly, p::PresentInputVoltage);
+    control_table_methods!(ReadOnly, p::PresentTemperature);
+    control_table_methods!(ReadWrite, p::ExternalPortData1);
+    control_table_methods!(ReadWrite, p::ExternalPortData2);
+    control_table_methods!(ReadWrite, p::ExternalPortData3);
+    control_table_methods!(ReadWrite, p::ExternalPortData4);
+
+    control_table_methods!(ReadWrite, ax::TemperatureLimit);
+    control_table_methods!(ReadWrite, ax::MinVoltageLimit);
+    control_table_methods!(ReadWrite, ax::MaxVoltageLimit);
+    control_table_methods!(ReadWrite, ax::MaxTorque);
+    control_table_methods!(ReadWrite, ax::StatusReturnLevel);
+    control_table_methods!(ReadWrite, ax::AlarmLed);
+    control_table_methods!(ReadWrite, ax::CwComplianceMargin);
+    control_table_methods!(ReadWrite, ax::CcwComplianceMargin);
+    control_table_methods!(ReadWrite, ax::CwComplianceSlope);
+    control_table_methods!(ReadWrite, ax::CcwComplianceSlope);
+    control_table_methods!(ReadWrite, ax::TorqueLimit);
+    control_table_methods!(ReadOnly, ax::PresentPosition);
+    control_table_methods!(ReadOnly, ax::PresentSpeed);
+    control_table_methods!(ReadOnly, ax::PresentLoad);
+    control_table_methods!(ReadOnly, ax::PresentVoltage);
+    control_table_methods!(ReadOnly, ax::PresentTemperature);
+    control_table_methods!(ReadOnly, ax::RegisteredInstruction);
+    control_table_methods!(ReadOnly, ax::Moving);
+    control_table_methods!(ReadWrite, ax::Lock);
+    control_table_methods!(ReadWrite, ax::Punch);
+
+    control_table_methods!(ReadWrite, mx::MultiTurnOffset);
+    control_table_methods!(ReadWrite, mx::ResolutionDivider);
+    control_table_methods!(ReadWrite, mx::DGain);
+    control_table_methods!(ReadWrite, mx::IGain);
+    control_table_methods!(ReadWrite, mx::PGain);
+    control_table_methods!(ReadOnly, mx::RealtimeTick);
+    control_table_methods!(ReadWrite, mx::GoalAcceleration);
+
+    control_table_methods!(ReadOnly, mx64::Current);
+    control_table_methods!(ReadWrite, mx64::TorqueControlModeEnable);
+    control_table_methods!(ReadWrite, mx64::GoalTorque);
 }
 
 impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> defmt::Format for Actuator<'bus, C, M> {
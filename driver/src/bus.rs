@@ -1,6 +1,11 @@
 use {
     crate::{comm::Comm, mutex::Mutex},
-    ::dxl_packet::{New, packet::recv::PersistentConfig},
+    ::dxl_packet::{
+        New,
+        list::{Cons, Nil},
+        packet::recv::{PersistentConfig, PersistentError, WithId},
+        stream::Stream,
+    },
     paste::paste,
 };
 
@@ -19,6 +24,62 @@ impl<C: Comm, Output> defmt::Format for Error<C, Output> {
     }
 }
 
+/// Failure of a combined response, which spoils every ID at once.
+pub enum FastError<C: Comm> {
+    Io(crate::IoError<C>),
+    Packet(::dxl_packet::packet::fast::Error),
+}
+
+impl<C: Comm> defmt::Format for FastError<C> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::Io(ref e) => defmt::Format::format(e, f),
+            Self::Packet(ref e) => defmt::write!(f, "Invalid combined response: {}", e),
+        }
+    }
+}
+
+pub enum FactoryResetError<C: Comm> {
+    Reset(Error<C, ::dxl_packet::recv::FactoryReset>),
+    Ping {
+        id: u8,
+        baud: u32,
+        error: Error<C, ::dxl_packet::recv::Ping>,
+    },
+}
+
+impl<C: Comm> defmt::Format for FactoryResetError<C> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::Reset(ref e) => defmt::write!(f, "Error requesting a factory reset: {}", e),
+            Self::Ping {
+                id,
+                baud,
+                ref error,
+            } => defmt::write!(
+                f,
+                "Reset actuator did not respond as ID {} at {} baud: {}",
+                id,
+                baud,
+                error
+            ),
+        }
+    }
+}
+
+/// How long to keep pinging a factory-reset actuator while it reboots.
+const RESET_WAIT_US: u32 = 1_000_000;
+
+/// Assumed for IDs whose return delay we haven't been told:
+/// the most any model allows (254 units of 2 µs).
+pub const MAX_RETURN_DELAY_US: u16 = 508;
+
+/// Allowance for an actuator to process an instruction before its return delay starts,
+/// plus our own scheduling, on top of every response window.
+const PROCESSING_US: u32 = 2_000;
+
 #[derive(defmt::Format)]
 #[cfg(debug_assertions)]
 pub enum IdError {
@@ -26,12 +87,313 @@ pub enum IdError {
     AlreadyInUse { id: u8 },
 }
 
+/// An instruction packet in either protocol.
+enum Packet<Insn: ::dxl_packet::Instruction> {
+    V1(::dxl_packet::packet::v1::WithChecksum<Insn>),
+    V2(::dxl_packet::packet::send::WithCrc<Insn>),
+}
+
+impl<Insn: ::dxl_packet::Instruction> Packet<Insn> {
+    #[inline(always)]
+    const fn as_buffer(&self) -> &[u8] {
+        match *self {
+            Self::V1(ref packet) => packet.as_buffer(),
+            Self::V2(ref packet) => packet.as_buffer(),
+        }
+    }
+}
+
+/// Every ID that answered a broadcast ping, with what it said.
+pub struct Responders {
+    pings: [Option<::dxl_packet::recv::Ping>; ::dxl_packet::N_IDS as usize],
+}
+
+impl Responders {
+    #[inline]
+    #[must_use]
+    pub fn get(&self, id: u8) -> Option<&::dxl_packet::recv::Ping> {
+        self.pings.get(usize::from(id))?.as_ref()
+    }
+
+    /// `(id, model_number, firmware_version)` for each responder, in order of ID.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u16, u8)> + '_ {
+        (::dxl_packet::MIN_ID..=::dxl_packet::MAX_ID)
+            .zip(&self.pings)
+            .filter_map(|(id, ping)| {
+                let &::dxl_packet::recv::Ping {
+                    model_number,
+                    firmware_version,
+                } = ping.as_ref()?;
+                Some((id, model_number, firmware_version))
+            })
+    }
+}
+
+/// How long `bytes` bytes take to send at `baud`, at 10 bits per byte.
+#[inline]
+#[must_use]
+pub const fn transmission_us(baud: u32, bytes: usize) -> u32 {
+    let total = (bytes as u64 * 10 * 1_000_000).div_ceil(baud as u64);
+    if total > u32::MAX as u64 {
+        u32::MAX
+    } else {
+        total as u32
+    }
+}
+
+/// How long every possible ID could take to answer a broadcast ping:
+/// one 14-byte status packet per ID at 10 bits per byte,
+/// plus the 3 ms each servo waits per ID ahead of it.
+#[inline]
+#[must_use]
+pub const fn broadcast_ping_window_us(baud: u32) -> u32 {
+    let n_ids = ::dxl_packet::N_IDS as u64;
+    let transmission = (14 * 10 * 1_000_000 * n_ids).div_ceil(baud as u64);
+    let stagger = 3_000 * n_ids;
+    let total = transmission + stagger;
+    if total > u32::MAX as u64 {
+        u32::MAX
+    } else {
+        total as u32
+    }
+}
+
 pub struct Bus<C: Comm> {
     pub comm: C,
+    protocol: ::dxl_packet::packet::Protocol,
+    /// Which instructions each ID answers, indexed by ID.
+    status_return_levels:
+        [::dxl_packet::control_table::value::StatusReturnLevel; ::dxl_packet::N_IDS as usize],
+    /// How long each ID waits before answering, indexed by ID.
+    return_delays_us: [u16; ::dxl_packet::N_IDS as usize],
     #[cfg(debug_assertions)]
     pub used_ids: [bool; dxl_packet::N_IDS as usize],
 }
 
+/// `id`'s entry in `levels`, or `All` for IDs without one (e.g. broadcast).
+#[inline]
+fn level_of(
+    levels: &[::dxl_packet::control_table::value::StatusReturnLevel],
+    id: u8,
+) -> ::dxl_packet::control_table::value::StatusReturnLevel {
+    levels
+        .get(usize::from(id))
+        .copied()
+        .unwrap_or(::dxl_packet::control_table::value::StatusReturnLevel::All)
+}
+
+/// Wait for a status packet from `expected_id`,
+/// parsed by `Parser` (e.g. `recv::WithCrc<Insn>` or `v1::Status<Insn::Recv>`).
+#[inline]
+async fn receive<
+    C: Comm,
+    Output,
+    E: defmt::Format,
+    Parser: ::dxl_packet::parse::State<
+            u8,
+            Output = WithId<Output>,
+            SideEffect = (),
+            Error = ::dxl_packet::packet::recv::Error<WithId<Output>, E>,
+            Config = (),
+        >,
+>(
+    stream: &mut impl Stream<Item = Result<u8, C::RecvError>>,
+    expected_id: u8,
+) -> Result<Output, Error<C, Output>> {
+    let mut state: ::dxl_packet::packet::recv::Persistent<Parser> =
+        <::dxl_packet::packet::recv::Persistent<Parser> as New>::new(PersistentConfig {
+            expected_id,
+        });
+    loop {
+        let byte: u8 = stream
+            .next()
+            .await
+            .map_err(|e| Error::Io(crate::IoError::Recv(e)))?;
+        state = match ::dxl_packet::parse::State::push(state, byte).map_err(Error::Packet)? {
+            ::dxl_packet::parse::Status::Complete(complete) => return Ok(complete),
+            ::dxl_packet::parse::Status::Incomplete((updated, ())) => updated,
+        };
+        let () = C::yield_to_other_tasks().await;
+    }
+}
+
+/// Decode a successful (or hardware-errored) read as `Address::Value`.
+#[inline]
+fn decode<C: Comm, Address: ::dxl_packet::control_table::Item>(
+    result: Result<
+        ::dxl_packet::recv::Read<{ Address::BYTES as usize }>,
+        Error<C, ::dxl_packet::recv::Read<{ Address::BYTES as usize }>>,
+    >,
+) -> Result<Address::Value, Error<C, Address::Value>>
+where
+    [(); Address::BYTES as usize]:,
+{
+    use ::dxl_packet::control_table::value::Value;
+    match result {
+        Ok(::dxl_packet::recv::Read { bytes }) => Ok(Address::Value::from_le_bytes(bytes)),
+        Err(Error::Io(e)) => Err(Error::Io(e)),
+        Err(Error::Packet(e)) => Err(Error::Packet(
+            e.map(|::dxl_packet::recv::Read { bytes }| Address::Value::from_le_bytes(bytes)),
+        )),
+    }
+}
+
+/// A response already checked to be `N` bytes wide, as a `recv::Read`.
+///
+/// # Panics
+/// If `bytes` isn't `N` bytes long; callers check the width first.
+#[inline]
+fn sized<const N: usize>(bytes: &[u8]) -> ::dxl_packet::recv::Read<N> {
+    match bytes.try_into() {
+        Ok(bytes) => ::dxl_packet::recv::Read { bytes },
+        Err(_) => unreachable!("`fill` only routes responses as wide as their entry"),
+    }
+}
+
+/// A `list::Cons` list of `send::BulkReadEntry`s,
+/// each of which gets its own result.
+pub trait BulkReadEntries<C: Comm>: ::dxl_packet::send::BulkReadEntries {
+    /// The widest entry, so one buffer fits any response.
+    const MAX_BYTES: usize;
+    /// One `Option` per entry, filled in as responses arrive.
+    type Slots;
+    type Results;
+    /// How long every entry's response could take, not counting `PROCESSING_US`.
+    fn response_us(&self, bus: &Bus<C>) -> u32;
+    /// Empty slots, except (with an error) for IDs whose level in `levels` means they won't answer.
+    fn slots(
+        &self,
+        levels: &[::dxl_packet::control_table::value::StatusReturnLevel],
+    ) -> Self::Slots;
+    /// The ID of the first entry still waiting for a response.
+    fn waiting(&self, slots: &Self::Slots) -> Option<u8>;
+    /// Fill the first empty slot for `id` whose width matches,
+    /// or return `false` if there isn't one.
+    fn fill(&self, slots: &mut Self::Slots, id: u8, result: Result<&[u8], Error<C, &[u8]>>)
+    -> bool;
+    fn results(slots: Self::Slots) -> Self::Results;
+}
+
+impl<C: Comm> BulkReadEntries<C> for Nil {
+    const MAX_BYTES: usize = 0;
+    type Slots = ();
+    type Results = ();
+
+    #[inline(always)]
+    fn response_us(&self, _: &Bus<C>) -> u32 {
+        0
+    }
+
+    #[inline(always)]
+    fn slots(&self, _: &[::dxl_packet::control_table::value::StatusReturnLevel]) {}
+
+    #[inline(always)]
+    fn waiting(&self, (): &()) -> Option<u8> {
+        None
+    }
+
+    #[inline(always)]
+    fn fill(&self, (): &mut (), _: u8, _: Result<&[u8], Error<C, &[u8]>>) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn results((): ()) {}
+}
+
+impl<C: Comm, Address: ::dxl_packet::control_table::Item, Tail: BulkReadEntries<C>>
+    BulkReadEntries<C> for Cons<::dxl_packet::send::BulkReadEntry<Address>, Tail>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const MAX_BYTES: usize = if Address::BYTES as usize > Tail::MAX_BYTES {
+        Address::BYTES as usize
+    } else {
+        Tail::MAX_BYTES
+    };
+    type Slots = (
+        Option<Result<Address::Value, Error<C, Address::Value>>>,
+        Tail::Slots,
+    );
+    type Results = (
+        Result<Address::Value, Error<C, Address::Value>>,
+        Tail::Results,
+    );
+
+    #[inline]
+    fn response_us(&self, bus: &Bus<C>) -> u32 {
+        bus.response_us(
+            self.head().id(),
+            ::dxl_packet::packet::Instruction::BulkRead as u8,
+            Address::BYTES.into(),
+        )
+        .saturating_add(self.tail().response_us(bus))
+    }
+
+    #[inline]
+    fn slots(
+        &self,
+        levels: &[::dxl_packet::control_table::value::StatusReturnLevel],
+    ) -> Self::Slots {
+        let id = self.head().id();
+        let level = level_of(levels, id);
+        let head = (!level.answers(::dxl_packet::packet::Instruction::BulkRead as u8)).then_some(
+            Err(Error::Io(crate::IoError::NoStatus {
+                id,
+                gerund: <::dxl_packet::send::Read<Address> as ::dxl_packet::Instruction>::GERUND,
+                level,
+            })),
+        );
+        (head, self.tail().slots(levels))
+    }
+
+    #[inline]
+    fn waiting(&self, (head, tail): &Self::Slots) -> Option<u8> {
+        match *head {
+            None => Some(self.head().id()),
+            Some(_) => self.tail().waiting(tail),
+        }
+    }
+
+    #[inline]
+    fn fill(
+        &self,
+        &mut (ref mut head, ref mut tail): &mut Self::Slots,
+        id: u8,
+        result: Result<&[u8], Error<C, &[u8]>>,
+    ) -> bool {
+        let fits = match result {
+            Ok(bytes)
+            | Err(Error::Packet(
+                PersistentError::Hardware(bytes) | PersistentError::HardwareDetailed(bytes, _),
+            )) => bytes.len() == usize::from(Address::BYTES),
+            Err(_) => true,
+        };
+        if head.is_some() || id != self.head().id() || !fits {
+            return self.tail().fill(tail, id, result);
+        }
+        *head = Some(decode::<C, Address>(match result {
+            Ok(bytes) => Ok(sized(bytes)),
+            Err(Error::Io(e)) => Err(Error::Io(e)),
+            Err(Error::Packet(e)) => Err(Error::Packet(e.map(sized))),
+        }));
+        true
+    }
+
+    #[inline]
+    fn results((head, tail): Self::Slots) -> Self::Results {
+        (
+            match head {
+                Some(result) => result,
+                // `Bus::bulk_read` only stops once every entry has a result:
+                None => unreachable!("every entry has a result once the bulk read ends"),
+            },
+            Tail::results(tail),
+        )
+    }
+}
+
 macro_rules! instruction_method {
     ($id:ident) => {
         #[inline]
@@ -55,44 +417,67 @@ macro_rules! instruction_method {
 }
 
 macro_rules! control_table_methods {
-    ($id:ident) => {
+    ($access:ident, $module:ident :: $id:ident) => {
+        paste! { control_table_methods!(@ $access, [< $module _ $id:snake >], ::dxl_packet::control_table::$module::$id); }
+    };
+    ($access:ident, $id:ident) => {
+        paste! { control_table_methods!(@ $access, [< $id:snake >], ::dxl_packet::control_table::$id); }
+    };
+    (@ ReadOnly, $name:ident, $item:ty) => {
         paste! {
             #[inline]
-            pub async fn [< read_ $id:snake >](
+            pub async fn [< read_ $name >](
                 &mut self,
                 id: u8,
-            ) -> Result<::dxl_packet::recv::Read<{ <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize }>, Error<C, ::dxl_packet::recv::Read<{ <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize }>>> {
-                self.comm::<::dxl_packet::send::Read<::dxl_packet::control_table::$id>>(
-                    id,
-                    ::dxl_packet::send::Read::<::dxl_packet::control_table::$id>::new()
-                )
-                .await
+            ) -> Result<<$item as ::dxl_packet::control_table::Item>::Value, Error<C, <$item as ::dxl_packet::control_table::Item>::Value>> {
+                self.read::<$item>(id).await
             }
 
             #[inline]
-            pub async fn [< write_ $id:snake >](
+            pub async fn [< sync_read_ $name >]<const N: usize>(
+                &mut self,
+                ids: [u8; N],
+            ) -> Result<[Result<<$item as ::dxl_packet::control_table::Item>::Value, Error<C, <$item as ::dxl_packet::control_table::Item>::Value>>; N], crate::IoError<C>> {
+                self.sync_read::<$item, N>(ids).await
+            }
+
+            #[inline]
+            pub async fn [< fast_sync_read_ $name >]<const N: usize>(
+                &mut self,
+                ids: [u8; N],
+            ) -> Result<[::dxl_packet::packet::fast::Portion<<$item as ::dxl_packet::control_table::Item>::Value>; N], FastError<C>> {
+                self.fast_sync_read::<$item, N>(ids).await
+            }
+        }
+    };
+    (@ ReadWrite, $name:ident, $item:ty) => {
+        control_table_methods!(@ ReadOnly, $name, $item);
+
+        paste! {
+            #[inline]
+            pub async fn [< write_ $name >](
                 &mut self,
                 id: u8,
-                bytes: [u8; <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize]
+                value: <$item as ::dxl_packet::control_table::Item>::Value,
             ) -> Result<::dxl_packet::recv::Write, Error<C, ::dxl_packet::recv::Write>> {
-                self.comm::<::dxl_packet::send::Write<::dxl_packet::control_table::$id, { <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize }>>(
-                    id,
-                    ::dxl_packet::send::Write::<::dxl_packet::control_table::$id, { <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize }>::new(bytes)
-                )
-                .await
+                self.write::<$item>(id, value).await
             }
 
             #[inline]
-            pub async fn [< reg_write_ $id:snake >](
+            pub async fn [< sync_write_ $name >]<const N: usize>(
+                &mut self,
+                entries: [(u8, <$item as ::dxl_packet::control_table::Item>::Value); N],
+            ) -> Result<(), crate::IoError<C>> {
+                self.sync_write::<$item, N>(entries).await
+            }
+
+            #[inline]
+            pub async fn [< reg_write_ $name >](
                 &mut self,
                 id: u8,
-                bytes: [u8; <::dxl_packet::control_table::$id as ::dxl_packet::control_table::Item>::BYTES as usize]
+                value: <$item as ::dxl_packet::control_table::Item>::Value,
             ) -> Result<::dxl_packet::recv::RegWrite, Error<C, ::dxl_packet::recv::RegWrite>> {
-                self.comm::<::dxl_packet::send::RegWrite<::dxl_packet::control_table::$id>>(
-                    id,
-                    ::dxl_packet::send::RegWrite::<::dxl_packet::control_table::$id>::new(bytes)
-                )
-                .await
+                self.reg_write::<$item>(id, value).await
             }
         }
     };
@@ -103,6 +488,10 @@ impl<C: Comm> Bus<C> {
     pub const fn new(comm: C) -> Self {
         Self {
             comm,
+            protocol: ::dxl_packet::packet::Protocol::V2,
+            status_return_levels: [::dxl_packet::control_table::value::StatusReturnLevel::All;
+                ::dxl_packet::N_IDS as usize],
+            return_delays_us: [MAX_RETURN_DELAY_US; ::dxl_packet::N_IDS as usize],
             #[cfg(debug_assertions)]
             used_ids: [false; dxl_packet::N_IDS as usize],
         }
@@ -126,96 +515,848 @@ impl<C: Comm> Bus<C> {
         self.comm.set_baud(baud)
     }
 
+    #[inline(always)]
+    pub fn baud(&self) -> u32 {
+        self.comm.baud()
+    }
+
+    /// Switch every subsequent packet to another wire format
+    /// (Protocol 2.0 by default).
+    #[inline(always)]
+    pub fn set_protocol(&mut self, protocol: ::dxl_packet::packet::Protocol) {
+        self.protocol = protocol
+    }
+
+    #[inline(always)]
+    pub const fn protocol(&self) -> ::dxl_packet::packet::Protocol {
+        self.protocol
+    }
+
+    /// Record which instructions `id` answers (`All` until told otherwise),
+    /// so that nothing waits for a status packet that won't come
+    /// and reads that would never be answered fail immediately.
+    /// This doesn't write anything to the actuator (`Actuator` keeps both in step).
+    #[inline]
+    pub fn set_status_return_level(
+        &mut self,
+        id: u8,
+        level: ::dxl_packet::control_table::value::StatusReturnLevel,
+    ) {
+        if let Some(slot) = self.status_return_levels.get_mut(usize::from(id)) {
+            *slot = level;
+        }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn status_return_level(
+        &self,
+        id: u8,
+    ) -> ::dxl_packet::control_table::value::StatusReturnLevel {
+        level_of(&self.status_return_levels, id)
+    }
+
+    /// Record how long `id` waits before answering (`ReturnDelayTime`, in µs),
+    /// which otherwise defaults to `MAX_RETURN_DELAY_US`, to tighten its response windows.
+    /// Like `set_status_return_level`, this doesn't write anything to the actuator.
+    #[inline]
+    pub fn set_return_delay_us(&mut self, id: u8, return_delay_us: u16) {
+        if let Some(slot) = self.return_delays_us.get_mut(usize::from(id)) {
+            *slot = return_delay_us;
+        }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn return_delay_us(&self, id: u8) -> u16 {
+        self.return_delays_us
+            .get(usize::from(id))
+            .copied()
+            .unwrap_or(MAX_RETURN_DELAY_US)
+    }
+
+    /// Bytes in a status packet carrying `parameters` bytes in this bus's protocol,
+    /// assuming the worst case for Protocol 2.0's byte stuffing (one extra byte in three).
+    #[inline]
+    const fn status_bytes(&self, parameters: usize) -> usize {
+        match self.protocol {
+            ::dxl_packet::packet::Protocol::V1 => 6 + parameters,
+            ::dxl_packet::packet::Protocol::V2 => 11 + parameters + parameters / 3,
+        }
+    }
+
+    /// How long `id` could take to finish answering `instruction` (a byte, as in `Instruction::BYTE`)
+    /// with `parameters` bytes, not counting `PROCESSING_US`, or 0 if it won't answer at all.
+    #[inline]
+    fn response_us(&self, id: u8, instruction: u8, parameters: usize) -> u32 {
+        if !self.status_return_level(id).answers(instruction) {
+            return 0;
+        }
+        transmission_us(self.baud(), self.status_bytes(parameters))
+            .saturating_add(self.return_delay_us(id).into())
+    }
+
+    /// How long reading `Address` from `id` waits for an answer,
+    /// e.g. to bound how often to poll something before giving up.
+    #[inline]
+    #[must_use]
+    pub fn read_window_us<Address: ::dxl_packet::control_table::Item>(&self, id: u8) -> u32 {
+        self.response_us(
+            id,
+            ::dxl_packet::packet::Instruction::Read as u8,
+            Address::BYTES.into(),
+        )
+        .saturating_add(PROCESSING_US)
+    }
+
+    /// Build an instruction packet in this bus's protocol.
+    #[inline]
+    fn packet<Insn: ::dxl_packet::Instruction>(
+        &self,
+        id: u8,
+        parameters: Insn,
+    ) -> Result<Packet<Insn>, crate::IoError<C>> {
+        match self.protocol {
+            ::dxl_packet::packet::Protocol::V2 => {
+                Ok(Packet::V2(::dxl_packet::packet::new(id, parameters)))
+            }
+            ::dxl_packet::packet::Protocol::V1 => {
+                ::dxl_packet::packet::v1::WithChecksum::new(id, parameters)
+                    .map(Packet::V1)
+                    .ok_or(crate::IoError::Unsupported {
+                        gerund: Insn::GERUND,
+                        protocol: self.protocol,
+                    })
+            }
+        }
+    }
+
     #[inline]
     pub async fn comm<Insn: ::dxl_packet::Instruction>(
         &mut self,
         id: u8,
         parameters: Insn,
     ) -> Result<Insn::Recv, Error<C, Insn::Recv>> {
+        let level = self.status_return_level(id);
+        if !level.answers(Insn::BYTE) {
+            // Only instructions whose responses carry nothing can succeed without one:
+            use ::dxl_packet::{parse, recv::Receive};
+            let parse::Status::Complete(output) =
+                <<Insn::Recv as Receive>::Parser as parse::MaybeParse<u8, _>>::init()
+            else {
+                return Err(Error::Io(crate::IoError::NoStatus {
+                    id,
+                    gerund: Insn::GERUND,
+                    level,
+                }));
+            };
+            let packet = self.packet(id, parameters).map_err(Error::Io)?;
+            defmt::debug!("Packet: {}", packet.as_buffer());
+            // Drop the response stream immediately, since nobody will respond:
+            let _ = self
+                .comm
+                .comm(packet.as_buffer(), 0)
+                .await
+                .map_err(crate::IoError::Send)
+                .map_err(Error::Io)?;
+            return Ok(output);
+        }
+        let protocol = self.protocol;
+        let window_us = self
+            .response_us(
+                id,
+                Insn::BYTE,
+                <Insn::Recv as ::dxl_packet::recv::Receive>::BYTES,
+            )
+            .saturating_add(PROCESSING_US);
         let mut stream = {
-            let packet = ::dxl_packet::packet::new::<Insn>(id, parameters);
+            let packet = self.packet(id, parameters).map_err(Error::Io)?;
             defmt::debug!("Packet: {}", packet.as_buffer());
             self.comm
-                .comm(packet.as_buffer())
+                .comm(packet.as_buffer(), window_us)
                 .await
                 .map_err(crate::IoError::Send)
                 .map_err(Error::Io)?
         };
-        let mut state: ::dxl_packet::packet::recv::Persistent<Insn> =
-            <::dxl_packet::packet::recv::Persistent<Insn> as New>::new(PersistentConfig {
-                expected_id: id,
-            });
+        match protocol {
+            ::dxl_packet::packet::Protocol::V2 => {
+                receive::<C, _, _, ::dxl_packet::packet::recv::WithCrc<Insn>>(&mut stream, id).await
+            }
+            ::dxl_packet::packet::Protocol::V1 => {
+                receive::<C, _, _, ::dxl_packet::packet::v1::Status<Insn::Recv>>(&mut stream, id)
+                    .await
+            }
+        }
+    }
+
+    /// Read an item, decoded as its `Value` (e.g. `i32` for `PresentPosition`).
+    #[inline]
+    pub async fn read<Address: ::dxl_packet::control_table::Item>(
+        &mut self,
+        id: u8,
+    ) -> Result<Address::Value, Error<C, Address::Value>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        decode::<C, Address>(
+            self.comm::<::dxl_packet::send::Read<Address>>(id, ::dxl_packet::send::Read::new())
+                .await,
+        )
+    }
+
+    #[inline]
+    pub async fn write<Address: ::dxl_packet::control_table::Writable>(
+        &mut self,
+        id: u8,
+        value: Address::Value,
+    ) -> Result<::dxl_packet::recv::Write, Error<C, ::dxl_packet::recv::Write>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        self.comm::<::dxl_packet::send::Write<Address, { Address::BYTES as usize }>>(
+            id,
+            ::dxl_packet::send::Write::new(value.to_le_bytes()),
+        )
+        .await
+    }
+
+    #[inline]
+    pub async fn reg_write<Address: ::dxl_packet::control_table::Writable>(
+        &mut self,
+        id: u8,
+        value: Address::Value,
+    ) -> Result<::dxl_packet::recv::RegWrite, Error<C, ::dxl_packet::recv::RegWrite>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        self.comm::<::dxl_packet::send::RegWrite<Address, { Address::BYTES as usize }>>(
+            id,
+            ::dxl_packet::send::RegWrite::new(value.to_le_bytes()),
+        )
+        .await
+    }
+
+    /// Read a run of contiguous items (e.g. `ActuatorState`) in one round trip.
+    #[inline(always)]
+    pub async fn read_snapshot<S: ::dxl_packet::control_table::Snapshot>(
+        &mut self,
+        id: u8,
+    ) -> Result<S, Error<C, S>>
+    where
+        [(); S::BYTES as usize]:,
+    {
+        self.read::<S>(id).await
+    }
+
+    /// Point `id`'s indirect addresses at `M`'s items (in EEPROM, so torque must be off),
+    /// after which `read_indirect::<M>` (or `sync_read::<M, N>`) fetches all of them at once.
+    #[inline]
+    pub async fn map_indirect<M: ::dxl_packet::control_table::indirect::Mapping>(
+        &mut self,
+        id: u8,
+    ) -> Result<::dxl_packet::recv::Write, Error<C, ::dxl_packet::recv::Write>>
+    where
+        [(); <M::Addresses as ::dxl_packet::control_table::Item>::BYTES as usize]:,
+    {
+        self.write::<M::Addresses>(id, ::dxl_packet::control_table::indirect::addresses::<M>())
+            .await
+    }
+
+    /// Whether `id`'s indirect addresses already point at `M`'s items.
+    #[inline]
+    pub async fn indirect_mapped<M: ::dxl_packet::control_table::indirect::Mapping>(
+        &mut self,
+        id: u8,
+    ) -> Result<bool, Error<C, <M::Addresses as ::dxl_packet::control_table::Item>::Value>>
+    where
+        [(); <M::Addresses as ::dxl_packet::control_table::Item>::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        let actual = self.read::<M::Addresses>(id).await?;
+        Ok(actual.to_le_bytes()
+            == ::dxl_packet::control_table::indirect::addresses::<M>().to_le_bytes())
+    }
+
+    /// Read `M`'s items through indirect data (see `map_indirect`) in one round trip.
+    #[inline(always)]
+    pub async fn read_indirect<M: ::dxl_packet::control_table::indirect::Mapping>(
+        &mut self,
+        id: u8,
+    ) -> Result<M::Value, Error<C, M::Value>>
+    where
+        [(); M::BYTES as usize]:,
+    {
+        self.read::<M>(id).await
+    }
+
+    /// Read the same item from each ID with a single packet,
+    /// waiting for each response in order without letting one missing ID spoil the rest.
+    #[inline]
+    pub async fn sync_read<Address: ::dxl_packet::control_table::Item, const N: usize>(
+        &mut self,
+        ids: [u8; N],
+    ) -> Result<[Result<Address::Value, Error<C, Address::Value>>; N], crate::IoError<C>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        use ::dxl_packet::{Instruction, packet::recv, parse};
+        type Insn<Address, const N: usize> = ::dxl_packet::send::SyncRead<Address, N>;
+        // IDs that won't answer are done before we start:
+        let mut results: [Option<_>; N] = core::array::from_fn(|i| {
+            let level = self.status_return_level(ids[i]);
+            (!level.answers(Insn::<Address, N>::BYTE)).then_some(Err(Error::Io(
+                crate::IoError::NoStatus {
+                    id: ids[i],
+                    gerund: Insn::<Address, N>::GERUND,
+                    level,
+                },
+            )))
+        });
+        // Each ID answers in turn, after the one before it:
+        let window_us = ids
+            .iter()
+            .map(|&id| self.response_us(id, Insn::<Address, N>::BYTE, Address::BYTES.into()))
+            .fold(PROCESSING_US, u32::saturating_add);
+        let mut stream = {
+            let packet = self.packet(::dxl_packet::BROADCAST_ID, Insn::<Address, N>::new(ids))?;
+            defmt::debug!("Packet: {}", packet.as_buffer());
+            self.comm
+                .comm(packet.as_buffer(), window_us)
+                .await
+                .map_err(crate::IoError::Send)?
+        };
+        // Responses arrive in the order of `ids`, so the first ID
+        // without a response is the one we're waiting to hear from:
+        while let Some(waiting) = results.iter().position(Option::is_none) {
+            let mut state = <recv::WithCrc<Insn<Address, N>> as New>::new(());
+            let (id, result) = 'bytes: loop {
+                let byte: u8 = match ::dxl_packet::stream::Stream::next(&mut stream).await {
+                    Ok(ok) => ok,
+                    Err(e) => {
+                        break 'bytes (ids[waiting], Err(Error::Io(crate::IoError::Recv(e))));
+                    }
+                };
+                state = match parse::State::push(state, byte) {
+                    Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                    Ok(parse::Status::Complete(WithId { id, output })) => {
+                        break 'bytes (id, Ok(output));
+                    }
+                    Err(recv::Error::Software(WithId { id, output })) => {
+                        break 'bytes (id, Err(Error::Packet(PersistentError::Software(output))));
+                    }
+                    Err(recv::Error::Hardware(WithId { id, output })) => {
+                        break 'bytes (id, Err(Error::Packet(PersistentError::Hardware(output))));
+                    }
+                    Err(e) => {
+                        defmt::warn!("{}; trying again...", e);
+                        <recv::WithCrc<Insn<Address, N>> as New>::new(())
+                    }
+                };
+                let () = C::yield_to_other_tasks().await;
+            };
+            match ids
+                .iter()
+                .zip(results.iter_mut())
+                .find(|&(&expected, ref result)| expected == id && result.is_none())
+            {
+                Some((_, slot)) => *slot = Some(result),
+                None => defmt::warn!("Unexpected response from ID {}; ignoring...", id),
+            }
+        }
+        Ok(results.map(|result| match result {
+            Some(result) => decode::<C, Address>(result),
+            // The loop above only ends once every ID has a result:
+            None => unreachable!("every ID has a result once the loop above ends"),
+        }))
+    }
+
+    /// Read a different item from each ID with a single packet,
+    /// e.g. `bus.bulk_read(list![BulkReadEntry::<PresentPosition>::new(1), ...])`,
+    /// without letting one missing ID spoil the rest.
+    #[inline]
+    pub async fn bulk_read<Entries: BulkReadEntries<C>>(
+        &mut self,
+        entries: Entries,
+    ) -> Result<Entries::Results, crate::IoError<C>>
+    where
+        [(); Entries::MAX_BYTES]:,
+    {
+        use ::dxl_packet::{packet::dynamic, packet::recv, parse};
+        // IDs that won't answer are done before we start:
+        let mut slots = entries.slots(&self.status_return_levels);
+        let window_us = entries.response_us(self).saturating_add(PROCESSING_US);
+        let mut stream = {
+            let packet = self.packet(
+                ::dxl_packet::BROADCAST_ID,
+                ::dxl_packet::send::BulkRead::new(entries),
+            )?;
+            defmt::debug!("Packet: {}", packet.as_buffer());
+            self.comm
+                .comm(packet.as_buffer(), window_us)
+                .await
+                .map_err(crate::IoError::Send)?
+        };
+        // Entries can differ in width, so parse any length and check it once we know the ID:
+        let mut buffer = [0; Entries::MAX_BYTES];
+        // Responses arrive in the order of `entries`, so the first entry
+        // without a response is the one we're waiting to hear from:
+        while let Some(waiting) = entries.waiting(&slots) {
+            let mut state = <dynamic::Status<'_> as New>::new(&mut buffer);
+            let (id, result) = 'bytes: loop {
+                let byte: u8 = match ::dxl_packet::stream::Stream::next(&mut stream).await {
+                    Ok(ok) => ok,
+                    Err(e) => break 'bytes (waiting, Err(Error::Io(crate::IoError::Recv(e)))),
+                };
+                state = match parse::State::push(state, byte) {
+                    Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                    Ok(parse::Status::Complete(WithId { id, output })) => {
+                        break 'bytes (id, Ok(output));
+                    }
+                    Err(recv::Error::Software(WithId { id, output })) => {
+                        break 'bytes (id, Err(Error::Packet(PersistentError::Software(output))));
+                    }
+                    Err(recv::Error::Hardware(WithId { id, output })) => {
+                        break 'bytes (id, Err(Error::Packet(PersistentError::Hardware(output))));
+                    }
+                    Err(e) => {
+                        defmt::warn!("{}; trying again...", e);
+                        <dynamic::Status<'_> as New>::new(&mut buffer)
+                    }
+                };
+                let () = C::yield_to_other_tasks().await;
+            };
+            if !entries.fill(&mut slots, id, result) {
+                defmt::warn!("Unexpected response from ID {}; ignoring...", id);
+            }
+        }
+        Ok(Entries::results(slots))
+    }
+
+    /// Send an instruction whose responses all arrive as one combined status packet,
+    /// retrying malformed packets until one parses or the stream times out.
+    #[inline]
+    pub async fn fast<Insn: ::dxl_packet::Instruction + ::dxl_packet::packet::fast::Portions>(
+        &mut self,
+        parameters: Insn,
+    ) -> Result<Insn::Output, FastError<C>> {
+        use ::dxl_packet::{packet::fast::Fast, parse};
+        // One silent ID would spoil the whole response, so don't bother:
+        let levels = &self.status_return_levels;
+        if let Some(id) = parameters.find_id(|id| !level_of(levels, id).answers(Insn::BYTE)) {
+            return Err(FastError::Io(crate::IoError::NoStatus {
+                id,
+                gerund: Insn::GERUND,
+                level: self.status_return_level(id),
+            }));
+        }
+        // One status packet (header, ID, length, and instruction, then `Insn::BYTES`, then CRC),
+        // after whichever return delay applies, so assume the longest:
+        let window_us = transmission_us(self.baud(), 10 + Insn::BYTES + Insn::BYTES / 3)
+            .saturating_add(MAX_RETURN_DELAY_US.into())
+            .saturating_add(PROCESSING_US);
+        let mut stream = {
+            let packet = self
+                .packet(::dxl_packet::BROADCAST_ID, parameters)
+                .map_err(FastError::Io)?;
+            defmt::debug!("Packet: {}", packet.as_buffer());
+            self.comm
+                .comm(packet.as_buffer(), window_us)
+                .await
+                .map_err(crate::IoError::Send)
+                .map_err(FastError::Io)?
+        };
+        let mut state = <Fast<Insn> as New>::new(());
         loop {
-            let byte: u8 = ::dxl_packet::stream::Stream::next(&mut stream)
+            let byte: u8 = stream
+                .next()
                 .await
-                .map_err(|e| Error::Io(crate::IoError::Recv(e)))?;
-            state = match ::dxl_packet::parse::State::push(state, byte).map_err(Error::Packet)? {
-                ::dxl_packet::parse::Status::Complete(complete) => return Ok(complete),
-                ::dxl_packet::parse::Status::Incomplete((updated, ())) => updated,
+                .map_err(|e| FastError::Io(crate::IoError::Recv(e)))?;
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Complete(complete)) => return Ok(complete),
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Err(e) => {
+                    defmt::warn!("{}; trying again...", e);
+                    <Fast<Insn> as New>::new(())
+                }
             };
             let () = C::yield_to_other_tasks().await;
         }
     }
 
-    instruction_method!(ping);
+    /// Like `sync_read`, but every ID answers in one combined status packet,
+    /// so a single missing ID spoils the whole response.
+    #[inline]
+    pub async fn fast_sync_read<Address: ::dxl_packet::control_table::Item, const N: usize>(
+        &mut self,
+        ids: [u8; N],
+    ) -> Result<[::dxl_packet::packet::fast::Portion<Address::Value>; N], FastError<C>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        let portions = self
+            .fast(::dxl_packet::send::FastSyncRead::<Address, N>::new(ids))
+            .await?;
+        Ok(portions.map(
+            |::dxl_packet::packet::fast::Portion {
+                 id,
+                 output,
+                 hardware_error,
+             }| ::dxl_packet::packet::fast::Portion {
+                id,
+                output: output
+                    .map(|::dxl_packet::recv::Read { bytes }| Address::Value::from_le_bytes(bytes)),
+                hardware_error,
+            },
+        ))
+    }
+
+    /// Like `bulk_read`, but every ID answers in one combined status packet,
+    /// so a single missing ID spoils the whole response.
+    #[inline]
+    pub async fn fast_bulk_read<
+        Entries: ::dxl_packet::send::BulkReadEntries + ::dxl_packet::packet::fast::Portions,
+    >(
+        &mut self,
+        entries: Entries,
+    ) -> Result<Entries::Output, FastError<C>> {
+        self.fast(::dxl_packet::send::FastBulkRead::new(entries))
+            .await
+    }
+
+    /// Write a different item to each ID with a single packet,
+    /// e.g. `bus.bulk_write(list![BulkWriteEntry::<GoalPosition>::new(1, 2048), ...])`.
+    #[inline]
+    pub async fn bulk_write<Entries: ::dxl_packet::send::BulkWriteEntries>(
+        &mut self,
+        entries: Entries,
+    ) -> Result<(), crate::IoError<C>> {
+        self.broadcast(::dxl_packet::send::BulkWrite::new(entries))
+            .await
+    }
+
+    /// Send a packet to every ID without waiting for any response.
+    #[inline]
+    pub async fn broadcast<Insn: ::dxl_packet::Instruction>(
+        &mut self,
+        parameters: Insn,
+    ) -> Result<(), crate::IoError<C>> {
+        let packet = self.packet(::dxl_packet::BROADCAST_ID, parameters)?;
+        defmt::debug!("Packet: {}", packet.as_buffer());
+        // Drop the response stream immediately, since nobody will respond:
+        let _ = self
+            .comm
+            .comm(packet.as_buffer(), 0)
+            .await
+            .map_err(crate::IoError::Send)?;
+        Ok(())
+    }
+
+    /// Write the same item to each ID with a single packet.
+    #[inline]
+    pub async fn sync_write<Address: ::dxl_packet::control_table::Writable, const N: usize>(
+        &mut self,
+        entries: [(u8, Address::Value); N],
+    ) -> Result<(), crate::IoError<C>>
+    where
+        [(); Address::BYTES as usize]:,
+    {
+        use ::dxl_packet::control_table::value::Value;
+        self.broadcast(::dxl_packet::send::SyncWrite::<Address, N>::new(
+            entries.map(|(id, value)| {
+                ::dxl_packet::send::SyncWriteEntry::new(id, value.to_le_bytes())
+            }),
+        ))
+        .await
+    }
+
+    /// Reset the multi-turn revolution count of `id` (which must not be moving).
+    #[inline]
+    pub async fn clear_multi_turn(
+        &mut self,
+        id: u8,
+    ) -> Result<::dxl_packet::recv::Clear, Error<C, ::dxl_packet::recv::Clear>> {
+        self.comm::<::dxl_packet::send::Clear>(id, ::dxl_packet::send::Clear::new())
+            .await
+    }
+
+    /// Store the control table of `id` to its backup area or restore it from there
+    /// (either way, torque must be off).
+    #[inline]
+    pub async fn control_table_backup(
+        &mut self,
+        id: u8,
+        operation: ::dxl_packet::send::Backup,
+    ) -> Result<
+        ::dxl_packet::recv::ControlTableBackup,
+        Error<C, ::dxl_packet::recv::ControlTableBackup>,
+    > {
+        self.comm::<::dxl_packet::send::ControlTableBackup>(
+            id,
+            ::dxl_packet::send::ControlTableBackup::new(operation),
+        )
+        .await
+    }
+
+    /// Reset the control table of `id` to factory defaults, then wait for it
+    /// to respond again at whichever ID and baud rate `scope` leaves it with
+    /// (temporarily switching the bus to that baud rate if necessary).
+    #[inline]
+    pub async fn factory_reset(
+        &mut self,
+        id: u8,
+        scope: ::dxl_packet::send::Reset,
+    ) -> Result<::dxl_packet::recv::Ping, FactoryResetError<C>> {
+        let () = self
+            .comm::<::dxl_packet::send::FactoryReset>(
+                id,
+                ::dxl_packet::send::FactoryReset::new(scope),
+            )
+            .await
+            .map_err(FactoryResetError::Reset)?;
+        let original_baud = self.baud();
+        let (old_id, id) = (id, scope.id_after(id));
+        let baud = scope.baud_after(original_baud);
+        // Whatever was recorded for either ID no longer holds: the reset restores both defaults.
+        for id in [old_id, id] {
+            let () = self.set_status_return_level(
+                id,
+                ::dxl_packet::control_table::value::StatusReturnLevel::All,
+            );
+            let () = self.set_return_delay_us(id, MAX_RETURN_DELAY_US);
+        }
+        if baud != original_baud {
+            let () = self.set_baud(baud);
+        }
+        // Each unanswered ping takes about one response window:
+        let ping_window_us = self
+            .response_us(
+                id,
+                ::dxl_packet::packet::Instruction::Ping as u8,
+                <::dxl_packet::recv::Ping as ::dxl_packet::recv::Receive>::BYTES,
+            )
+            .saturating_add(PROCESSING_US);
+        let max_attempts = RESET_WAIT_US.div_ceil(ping_window_us);
+        let mut attempts = 0;
+        let result = loop {
+            attempts += 1;
+            match self.ping(id).await {
+                // Still rebooting:
+                Err(Error::Io(crate::IoError::Recv(_))) if attempts < max_attempts => {}
+                result => break result,
+            }
+            let () = C::yield_to_other_tasks().await;
+        };
+        if baud != original_baud {
+            let () = self.set_baud(original_baud);
+        }
+        result.map_err(|error| FactoryResetError::Ping { id, baud, error })
+    }
+
+    /// Under Protocol 1.0, whose pings carry no information,
+    /// read the model number and firmware version directly instead.
+    #[inline]
+    pub async fn ping(
+        &mut self,
+        id: u8,
+    ) -> Result<::dxl_packet::recv::Ping, Error<C, ::dxl_packet::recv::Ping>> {
+        match self.protocol {
+            ::dxl_packet::packet::Protocol::V2 => {
+                self.comm::<::dxl_packet::send::Ping>(id, ::dxl_packet::send::Ping::new())
+                    .await
+            }
+            ::dxl_packet::packet::Protocol::V1 => {
+                // Pings are always answered, even though this is a read:
+                let window_us = transmission_us(
+                    self.baud(),
+                    self.status_bytes(
+                        <::dxl_packet::recv::Ping as ::dxl_packet::recv::Receive>::BYTES,
+                    ),
+                )
+                .saturating_add(self.return_delay_us(id).into())
+                .saturating_add(PROCESSING_US);
+                let mut stream = {
+                    let packet = self
+                        .packet(
+                            id,
+                            ::dxl_packet::send::Read::<
+                                ::dxl_packet::packet::v1::ModelNumberAndFirmwareVersion,
+                            >::new(),
+                        )
+                        .map_err(Error::Io)?;
+                    defmt::debug!("Packet: {}", packet.as_buffer());
+                    self.comm
+                        .comm(packet.as_buffer(), window_us)
+                        .await
+                        .map_err(crate::IoError::Send)
+                        .map_err(Error::Io)?
+                };
+                receive::<C, _, _, ::dxl_packet::packet::v1::Status<::dxl_packet::recv::Ping>>(
+                    &mut stream,
+                    id,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Ping every ID at once and collect whoever answers before
+    /// `broadcast_ping_window_us` runs out. Protocol 1.0 servos answer a broadcast ping
+    /// all at once (i.e. colliding), so under Protocol 1.0 this pings each ID in turn.
+    #[inline]
+    pub async fn broadcast_ping(&mut self) -> Result<Responders, crate::IoError<C>> {
+        let mut responders = Responders {
+            pings: core::array::from_fn(|_| None),
+        };
+        if self.protocol == ::dxl_packet::packet::Protocol::V1 {
+            for id in ::dxl_packet::MIN_ID..=::dxl_packet::MAX_ID {
+                match self.ping(id).await {
+                    Ok(ping) => responders.pings[usize::from(id)] = Some(ping),
+                    Err(Error::Io(crate::IoError::Recv(_))) => {}
+                    Err(Error::Io(e)) => return Err(e),
+                    Err(Error::Packet(e)) => {
+                        defmt::warn!("ID {} answered a ping with an error: {}", id, e);
+                    }
+                }
+            }
+            return Ok(responders);
+        }
+
+        let window_us = broadcast_ping_window_us(self.comm.baud());
+        let mut stream = {
+            let packet =
+                self.packet(::dxl_packet::BROADCAST_ID, ::dxl_packet::send::Ping::new())?;
+            defmt::debug!("Packet: {}", packet.as_buffer());
+            self.comm
+                .comm(packet.as_buffer(), window_us)
+                .await
+                .map_err(crate::IoError::Send)?
+        };
+        let mut state =
+            <::dxl_packet::packet::recv::WithCrc<::dxl_packet::send::Ping> as New>::new(());
+        // The window closing is the only way this ends:
+        while let Ok(byte) = stream.next().await {
+            state = match ::dxl_packet::parse::State::push(state, byte) {
+                Ok(::dxl_packet::parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(::dxl_packet::parse::Status::Complete(WithId { id, output }))
+                | Err(::dxl_packet::packet::recv::Error::Hardware(WithId { id, output })) => {
+                    responders.pings[usize::from(id)] = Some(output);
+                    New::new(())
+                }
+                Err(e) => {
+                    defmt::warn!("Ignoring a malformed broadcast ping response: {}", e);
+                    New::new(())
+                }
+            };
+            let () = C::yield_to_other_tasks().await;
+        }
+        Ok(responders)
+    }
+
     instruction_method!(action);
-    instruction_method!(factory_reset);
     instruction_method!(reboot);
 
-    control_table_methods!(ModelNumber);
-    control_table_methods!(ModelInformation);
-    control_table_methods!(FirmwareVersion);
-    control_table_methods!(Id);
-    control_table_methods!(BaudRate);
-    control_table_methods!(ReturnDelayTime);
-    control_table_methods!(DriveMode);
-    control_table_methods!(OperatingMode);
-    control_table_methods!(SecondaryId);
-    control_table_methods!(ProtocolType);
-    control_table_methods!(HomingOffset);
-    control_table_methods!(MovingThreshold);
-    control_table_methods!(TemperatureLimit);
-    control_table_methods!(MaxVoltageLimit);
-    control_table_methods!(MinVoltageLimit);
-    control_table_methods!(PwmLimit);
-    control_table_methods!(CurrentLimit);
-    control_table_methods!(VelocityLimit);
-    control_table_methods!(MaxPositionLimit);
-    control_table_methods!(MinPositionLimit);
-    control_table_methods!(StartupConfiguration);
-    control_table_methods!(PwmSlope);
-    control_table_methods!(Shutdown);
-    control_table_methods!(TorqueEnable);
-    control_table_methods!(Led);
-    control_table_methods!(StatusReturnLevel);
-    control_table_methods!(RegisteredInstruction);
-    control_table_methods!(HardwareErrorStatus);
-    control_table_methods!(VelocityIGain);
-    control_table_methods!(VelocityPGain);
-    control_table_methods!(PositionDGain);
-    control_table_methods!(PositionIGain);
-    control_table_methods!(PositionPGain);
-    control_table_methods!(Feedforward2ndGain);
-    control_table_methods!(Feedforward1stGain);
-    control_table_methods!(BusWatchdog);
-    control_table_methods!(GoalPwm);
-    control_table_methods!(GoalCurrent);
-    control_table_methods!(GoalVelocity);
-    control_table_methods!(ProfileAcceleration);
-    control_table_methods!(ProfileVelocity);
-    control_table_methods!(GoalPosition);
-    control_table_methods!(RealtimeTick);
-    control_table_methods!(Moving);
-    control_table_methods!(MovingStatus);
-    control_table_methods!(PresentPwm);
-    control_table_methods!(PresentCurrent);
-    control_table_methods!(PresentVelocity);
-    control_table_methods!(PresentPosition);
-    control_table_methods!(VelocityTrajectory);
-    control_table_methods!(PositionTrajectory);
-    control_table_methods!(PresentInputVoltage);
-    control_table_methods!(PresentTemperature);
-    control_table_methods!(BackupReady);
+    control_table_methods!(ReadOnly, ModelNumber);
+    control_table_methods!(ReadOnly, ModelInformation);
+    control_table_methods!(ReadOnly, FirmwareVersion);
+    control_table_methods!(ReadWrite, Id);
+    control_table_methods!(ReadWrite, BaudRate);
+    control_table_methods!(ReadWrite, ReturnDelayTime);
+    control_table_methods!(ReadWrite, DriveMode);
+    control_table_methods!(ReadWrite, OperatingMode);
+    control_table_methods!(ReadWrite, SecondaryId);
+    control_table_methods!(ReadWrite, ProtocolType);
+    control_table_methods!(ReadWrite, HomingOffset);
+    control_table_methods!(ReadWrite, MovingThreshold);
+    control_table_methods!(ReadWrite, TemperatureLimit);
+    control_table_methods!(ReadWrite, MaxVoltageLimit);
+    control_table_methods!(ReadWrite, MinVoltageLimit);
+    control_table_methods!(ReadWrite, PwmLimit);
+    control_table_methods!(ReadWrite, CurrentLimit);
+    control_table_methods!(ReadWrite, VelocityLimit);
+    control_table_methods!(ReadWrite, MaxPositionLimit);
+    control_table_methods!(ReadWrite, MinPositionLimit);
+    control_table_methods!(ReadWrite, ExternalPortMode1);
+    control_table_methods!(ReadWrite, ExternalPortMode2);
+    control_table_methods!(ReadWrite, ExternalPortMode3);
+    control_table_methods!(ReadWrite, StartupConfiguration);
+    control_table_methods!(ReadWrite, PwmSlope);
+    control_table_methods!(ReadWrite, Shutdown);
+    control_table_methods!(ReadWrite, TorqueEnable);
+    control_table_methods!(ReadWrite, Led);
+    control_table_methods!(ReadWrite, StatusReturnLevel);
+    control_table_methods!(ReadOnly, RegisteredInstruction);
+    control_table_methods!(ReadOnly, HardwareErrorStatus);
+    control_table_methods!(ReadWrite, VelocityIGain);
+    control_table_methods!(ReadWrite, VelocityPGain);
+    control_table_methods!(ReadWrite, PositionDGain);
+    control_table_methods!(ReadWrite, PositionIGain);
+    control_table_methods!(ReadWrite, PositionPGain);
+    control_table_methods!(ReadWrite, Feedforward2ndGain);
+    control_table_methods!(ReadWrite, Feedforward1stGain);
+    control_table_methods!(ReadWrite, BusWatchdog);
+    control_table_methods!(ReadWrite, GoalPwm);
+    control_table_methods!(ReadWrite, GoalCurrent);
+    control_table_methods!(ReadWrite, GoalVelocity);
+    control_table_methods!(ReadWrite, ProfileAcceleration);
+    control_table_methods!(ReadWrite, ProfileVelocity);
+    control_table_methods!(ReadWrite, GoalPosition);
+    control_table_methods!(ReadOnly, RealtimeTick);
+    control_table_methods!(ReadOnly, Moving);
+    control_table_methods!(ReadOnly, MovingStatus);
+    control_table_methods!(ReadOnly, PresentPwm);
+    control_table_methods!(ReadOnly, PresentCurrent);
+    control_table_methods!(ReadOnly, PresentVelocity);
+    control_table_methods!(ReadOnly, PresentPosition);
+    control_table_methods!(ReadOnly, VelocityTrajectory);
+    control_table_methods!(ReadOnly, PositionTrajectory);
+    control_table_methods!(ReadOnly, PresentInputVoltage);
+    control_table_methods!(ReadOnly, PresentTemperature);
+    control_table_methods!(ReadOnly, BackupReady);
+    control_table_methods!(ReadWrite, ExternalPortData1);
+    control_table_methods!(ReadWrite, ExternalPortData2);
+    control_table_methods!(ReadWrite, ExternalPortData3);
+
+    control_table_methods!(ReadWrite, p::AccelerationLimit);
+    control_table_methods!(ReadWrite, p::ExternalPortMode4);
+    control_table_methods!(ReadWrite, p::TorqueEnable);
+    control_table_methods!(ReadWrite, p::LedRed);
+    control_table_methods!(ReadWrite, p::LedGreen);
+    control_table_methods!(ReadWrite, p::LedBlue);
+    control_table_methods!(ReadWrite, p::StatusReturnLevel);
+    control_table_methods!(ReadOnly, p::RegisteredInstruction);
+    control_table_methods!(ReadOnly, p::HardwareErrorStatus);
+    control_table_methods!(ReadWrite, p::VelocityIGain);
+    control_table_methods!(ReadWrite, p::VelocityPGain);
+    control_table_methods!(ReadWrite, p::PositionDGain);
+    control_table_methods!(ReadWrite, p::PositionIGain);
+    control_table_methods!(ReadWrite, p::PositionPGain);
+    control_table_methods!(ReadWrite, p::Feedforward2ndGain);
+    control_table_methods!(ReadWrite, p::Feedforward1stGain);
+    control_table_methods!(ReadWrite, p::BusWatchdog);
+    control_table_methods!(ReadWrite, p::GoalPwm);
+    control_table_methods!(ReadWrite, p::GoalCurrent);
+    control_table_methods!(ReadWrite, p::GoalVelocity);
+    control_table_methods!(ReadWrite, p::ProfileAcceleration);
+    control_table_methods!(ReadWrite, p::ProfileVelocity);
+    control_table_methods!(ReadWrite, p::GoalPosition);
+    control_table_methods!(ReadOnly, p::RealtimeTick);
+    control_table_methods!(ReadOnly, p::Moving);
+    control_table_methods!(ReadOnly, p::MovingStatus);
+    control_table_methods!(ReadOnly, p::PresentPwm);
+    control_table_methods!(ReadOnly, p::PresentCurrent);
+    control_table_methods!(ReadOnly, p::PresentVelocity);
+    control_table_methods!(ReadOnly, p::PresentPosition);
+    control_table_methods!(ReadOnly, p::VelocityTrajectory);
+    control_table_methods!(ReadOnly, p::PositionTrajectory);
+    control_table_methods!(ReadOnly, p::PresentInputVoltage);
+    control_table_methods!(ReadOnly, p::PresentTemperature);
+    control_table_methods!(ReadWrite, p::ExternalPortData1);
+    control_table_methods!(ReadWrite, p::ExternalPortData2);
+    control_table_methods!(ReadWrite, p::ExternalPortData3);
+    control_table_methods!(ReadWrite, p::ExternalPortData4);
 }
 
 const SCAN_BAUD: &[u32] = &[
@@ -225,7 +1366,8 @@ const SCAN_BAUD: &[u32] = &[
 pub struct Scan<'bus, C: Comm, M: Mutex<Item = Bus<C>>> {
     bus: &'bus M,
     baud_index: usize,
-    id: u8,
+    /// Who answered at the current baud, and the next ID to report.
+    responders: Option<(Responders, u8)>,
 }
 
 pub struct ScanFound<'bus, C: Comm, M: Mutex<Item = Bus<C>>> {
@@ -242,50 +1384,48 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Scan<'bus, C, M> {
         Self {
             bus,
             baud_index: usize::MAX,
-            id: dxl_packet::MAX_ID,
+            responders: None,
         }
     }
 
     #[inline]
     pub async fn next(mut self) -> Option<ScanFound<'bus, C, M>> {
         loop {
-            if self.id == dxl_packet::MAX_ID {
-                self.baud_index = self.baud_index.wrapping_add(1);
-                let baud = *SCAN_BAUD.get(self.baud_index)?;
-                defmt::info!("");
-                defmt::info!("Scanning at {} baud:", baud);
-                let mut bus = self.bus.lock_persistent().await;
-                let () = bus.set_baud(baud);
-                self.id = dxl_packet::MIN_ID;
+            let found =
+                self.responders
+                    .as_mut()
+                    .and_then(|&mut (ref responders, ref mut next_id)| {
+                        let found = responders.iter().find(|&(id, ..)| id >= *next_id)?;
+                        *next_id = found.0.saturating_add(1);
+                        Some(found)
+                    });
+            if let Some((id, model_number, firmware_version)) = found {
+                return Some(ScanFound {
+                    baud: SCAN_BAUD[self.baud_index],
+                    id,
+                    model_number,
+                    firmware_version,
+                    continue_scanning: self,
+                });
             }
 
-            'ignore_unrelated_errors: loop {
-                let response = {
-                    let mut bus = self.bus.lock_persistent().await;
-                    bus.ping(self.id).await
-                };
-                match response {
-                    Ok(dxl_packet::recv::Ping {
-                        model_number,
-                        firmware_version,
-                    }) => {
-                        return Some(ScanFound {
-                            baud: SCAN_BAUD[self.baud_index],
-                            id: self.id,
-                            model_number,
-                            firmware_version,
-                            continue_scanning: self,
-                        });
-                    }
-                    Err(Error::Io(crate::IoError::Recv(_))) => break 'ignore_unrelated_errors,
+            self.baud_index = self.baud_index.wrapping_add(1);
+            let baud = *SCAN_BAUD.get(self.baud_index)?;
+            defmt::info!("");
+            defmt::info!("Scanning at {} baud:", baud);
+            let mut bus = self.bus.lock_persistent().await;
+            let () = bus.set_baud(baud);
+            let responders = loop {
+                match bus.broadcast_ping().await {
+                    Ok(responders) => break responders,
                     Err(e) => defmt::warn!(
-                        "Unrelated error while scanning ID {} at {} baud: {}; trying again...",
-                        self.id,
-                        SCAN_BAUD[self.baud_index],
+                        "Unrelated error while scanning at {} baud: {}; trying again...",
+                        baud,
                         e,
                     ),
                 }
-            }
+            };
+            self.responders = Some((responders, ::dxl_packet::MIN_ID));
         }
     }
 
@@ -300,3 +1440,76 @@ impl<'bus, C: Comm, M: Mutex<Item = Bus<C>>> Scan<'bus, C, M> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        ::dxl_packet::{
+            control_table::{PresentPosition, PresentTemperature},
+            send::BulkReadEntry,
+        },
+    };
+
+    /// Replays canned responses, then times out.
+    struct Mock {
+        responses: Vec<u8>,
+    }
+
+    struct Replay<'rx>(std::vec::Drain<'rx, u8>);
+
+    impl Stream for Replay<'_> {
+        type Item = Result<u8, ()>;
+
+        async fn next(&mut self) -> Self::Item {
+            self.0.next().ok_or(())
+        }
+    }
+
+    impl Comm for Mock {
+        type SendError = ();
+        type RecvError = ();
+
+        async fn comm<'rx>(
+            &'rx mut self,
+            _: &[u8],
+            _: u32,
+        ) -> Result<impl 'rx + Stream<Item = Result<u8, ()>>, ()> {
+            Ok(Replay(self.responses.drain(..)))
+        }
+
+        fn set_baud(&mut self, _: u32) {}
+
+        fn baud(&self) -> u32 {
+            57_600
+        }
+
+        async fn yield_to_other_tasks() {}
+
+        fn listen<'rx>(&'rx mut self) -> impl 'rx + Stream<Item = Result<u8, ()>> {
+            Replay(self.responses.drain(..))
+        }
+    }
+
+    #[test]
+    fn bulk_read_skips_silent_id() {
+        let mut responses = vec![0; ::dxl_packet::packet::dynamic::max_size(2)];
+        // No error, then a temperature of 37 from ID 2:
+        let Ok(size) = ::dxl_packet::packet::dynamic::write(&mut responses, 2, 0x55, &[0x00, 37])
+        else {
+            panic!("couldn't write a status packet");
+        };
+        responses.truncate(size);
+        let mut bus = Bus::new(Mock { responses });
+        let Ok((position, (temperature, ()))) =
+            embassy_futures::block_on(bus.bulk_read(::dxl_packet::list![
+                BulkReadEntry::<PresentPosition>::new(1),
+                BulkReadEntry::<PresentTemperature>::new(2),
+            ]))
+        else {
+            panic!("couldn't send the bulk read");
+        };
+        assert!(matches!(position, Err(Error::Io(crate::IoError::Recv(())))));
+        assert!(matches!(temperature, Ok(37)));
+    }
+}
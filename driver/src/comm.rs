@@ -4,11 +4,15 @@ use dxl_packet::stream::Stream;
 pub trait Comm {
     type SendError: defmt::Format;
     type RecvError: defmt::Format;
+    /// Send `buffer`, then listen until `window_us` microseconds after it's sent
+    /// (the bus works out how long each response could take).
     async fn comm<'rx>(
         &'rx mut self,
         buffer: &[u8],
+        window_us: u32,
     ) -> Result<impl 'rx + Stream<Item = Result<u8, Self::RecvError>>, Self::SendError>;
     fn set_baud(&mut self, baud: u32);
+    fn baud(&self) -> u32;
     async fn yield_to_other_tasks();
     fn listen<'rx>(&'rx mut self) -> impl 'rx + Stream<Item = Result<u8, Self::RecvError>>;
 }
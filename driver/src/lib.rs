@@ -13,6 +13,18 @@ pub mod mutex;
 pub enum IoError<C: comm::Comm> {
     Send(<C as comm::Comm>::SendError),
     Recv(<C as comm::Comm>::RecvError),
+    /// The bus's protocol has no way to express this instruction.
+    Unsupported {
+        gerund: &'static str,
+        protocol: dxl_packet::packet::Protocol,
+    },
+    /// This ID's status return level means it won't answer, so there's nothing to return
+    /// (see `Bus::set_status_return_level`).
+    NoStatus {
+        id: u8,
+        gerund: &'static str,
+        level: dxl_packet::control_table::value::StatusReturnLevel,
+    },
 }
 
 impl<C: comm::Comm> defmt::Format for IoError<C> {
@@ -21,6 +33,19 @@ impl<C: comm::Comm> defmt::Format for IoError<C> {
         match *self {
             Self::Send(ref e) => defmt::write!(f, "Error while sending a packet: {}", e),
             Self::Recv(ref e) => defmt::write!(f, "Error while receiving a packet: {}", e),
+            Self::Unsupported { gerund, protocol } => defmt::write!(
+                f,
+                "{} is unsupported in {} (or its parameters don't fit)",
+                gerund,
+                protocol
+            ),
+            Self::NoStatus { id, gerund, level } => defmt::write!(
+                f,
+                "{} ID {} gets no status packet at status return level {}",
+                gerund,
+                id,
+                level
+            ),
         }
     }
 }
@@ -47,6 +72,25 @@ impl<C: comm::Comm, M: mutex::Mutex, Output> defmt::Format for BusError<C, M, Ou
 pub enum ActuatorError<C: comm::Comm, M: mutex::Mutex> {
     Mutex(<M as mutex::Mutex>::Error),
     Packet(actuator::Error<C>),
+    /// This model's control table has no such item.
+    Unsupported {
+        item: &'static str,
+        model: &'static str,
+    },
+    /// We don't know what this model's raw values mean in physical units.
+    UnknownUnits {
+        quantity: &'static str,
+        model: &'static str,
+    },
+    /// A write with torque temporarily off failed, and so did turning torque back on.
+    TorqueLeftOff {
+        write: actuator::Error<C>,
+        torque: actuator::Error<C>,
+    },
+    /// `BackupReady` never confirmed a control table backup.
+    BackupTimeout {
+        operation: dxl_packet::send::Backup,
+    },
 }
 
 impl<C: comm::Comm, M: mutex::Mutex> defmt::Format for ActuatorError<C, M> {
@@ -59,6 +103,24 @@ impl<C: comm::Comm, M: mutex::Mutex> defmt::Format for ActuatorError<C, M> {
                 e,
             ),
             Self::Packet(ref e) => defmt::write!(f, "Error from the Dynamixel serial bus: {}", e),
+            Self::Unsupported { item, model } => {
+                defmt::write!(f, "{} has no \"{}\" in its control table", model, item)
+            }
+            Self::UnknownUnits { quantity, model } => {
+                defmt::write!(f, "Unknown units of {} for {}", quantity, model)
+            }
+            Self::TorqueLeftOff {
+                ref write,
+                ref torque,
+            } => defmt::write!(
+                f,
+                "Error while writing with torque off: {}; then, error turning torque back on: {}",
+                write,
+                torque
+            ),
+            Self::BackupTimeout { operation } => {
+                defmt::write!(f, "Control table backup ({}) never became ready", operation)
+            }
         }
     }
 }
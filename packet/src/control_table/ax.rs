@@ -0,0 +1,267 @@
+//! AX-12A, AX-18A, and AX-12W: Protocol 1.0, in the layout the XL-320's was adapted from,
+//! so items at the same address as the XL-320's are the XL-320's own.
+
+use {
+    super::{Access, Area, Item, Writable, units::Quantity, xl320},
+    crate::control_table::model::{Table, entries},
+};
+
+pub struct TemperatureLimit;
+impl Item for TemperatureLimit {
+    const ADDRESS: u16 = 11;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Temperature Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+impl Writable for TemperatureLimit {}
+
+pub struct MinVoltageLimit;
+impl Item for MinVoltageLimit {
+    const ADDRESS: u16 = 12;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Min Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+impl Writable for MinVoltageLimit {}
+
+pub struct MaxVoltageLimit;
+impl Item for MaxVoltageLimit {
+    const ADDRESS: u16 = 13;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Max Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+impl Writable for MaxVoltageLimit {}
+
+pub struct MaxTorque;
+impl Item for MaxTorque {
+    const ADDRESS: u16 = 14;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Max Torque";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u16;
+}
+impl Writable for MaxTorque {}
+
+pub struct StatusReturnLevel;
+impl Item for StatusReturnLevel {
+    const ADDRESS: u16 = 16;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Status Return Level";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = super::value::StatusReturnLevel;
+}
+impl Writable for StatusReturnLevel {}
+
+pub struct AlarmLed;
+impl Item for AlarmLed {
+    const ADDRESS: u16 = 17;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Alarm LED";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for AlarmLed {}
+
+pub struct CwComplianceMargin;
+impl Item for CwComplianceMargin {
+    const ADDRESS: u16 = 26;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "CW Compliance Margin";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for CwComplianceMargin {}
+
+pub struct CcwComplianceMargin;
+impl Item for CcwComplianceMargin {
+    const ADDRESS: u16 = 27;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "CCW Compliance Margin";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for CcwComplianceMargin {}
+
+pub struct CwComplianceSlope;
+impl Item for CwComplianceSlope {
+    const ADDRESS: u16 = 28;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "CW Compliance Slope";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for CwComplianceSlope {}
+
+pub struct CcwComplianceSlope;
+impl Item for CcwComplianceSlope {
+    const ADDRESS: u16 = 29;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "CCW Compliance Slope";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for CcwComplianceSlope {}
+
+pub struct TorqueLimit;
+impl Item for TorqueLimit {
+    const ADDRESS: u16 = 34;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Torque Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for TorqueLimit {}
+
+pub struct PresentPosition;
+impl Item for PresentPosition {
+    const ADDRESS: u16 = 36;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Position";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u16;
+}
+
+pub struct PresentSpeed;
+impl Item for PresentSpeed {
+    const ADDRESS: u16 = 38;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Speed";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct PresentLoad;
+impl Item for PresentLoad {
+    const ADDRESS: u16 = 40;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Load";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct PresentVoltage;
+impl Item for PresentVoltage {
+    const ADDRESS: u16 = 42;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Voltage";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+
+pub struct PresentTemperature;
+impl Item for PresentTemperature {
+    const ADDRESS: u16 = 43;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Temperature";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+
+pub struct RegisteredInstruction;
+impl Item for RegisteredInstruction {
+    const ADDRESS: u16 = 44;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Registered Instruction";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct Moving;
+impl Item for Moving {
+    const ADDRESS: u16 = 46;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+/// Once set, EEPROM can't be written until the next power cycle.
+pub struct Lock;
+impl Item for Lock {
+    const ADDRESS: u16 = 47;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Lock";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for Lock {}
+
+pub struct Punch;
+impl Item for Punch {
+    const ADDRESS: u16 = 48;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Punch";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Punch {}
+
+pub const TABLE: Table = Table {
+    name: "AX-12A / AX-18A / AX-12W",
+    model_numbers: &[12, 18, 44],
+    items: entries![
+        xl320::ModelNumber,
+        xl320::FirmwareVersion,
+        xl320::Id,
+        xl320::BaudRate,
+        xl320::ReturnDelayTime,
+        xl320::CwAngleLimit,
+        xl320::CcwAngleLimit,
+        TemperatureLimit,
+        MinVoltageLimit,
+        MaxVoltageLimit,
+        MaxTorque,
+        StatusReturnLevel,
+        AlarmLed,
+        xl320::Shutdown,
+        xl320::TorqueEnable,
+        xl320::Led,
+        CwComplianceMargin,
+        CcwComplianceMargin,
+        CwComplianceSlope,
+        CcwComplianceSlope,
+        xl320::GoalPosition,
+        xl320::MovingSpeed,
+        TorqueLimit,
+        PresentPosition,
+        PresentSpeed,
+        PresentLoad,
+        PresentVoltage,
+        PresentTemperature,
+        RegisteredInstruction,
+        Moving,
+        Lock,
+        Punch,
+    ],
+    indirect: None,
+};
@@ -0,0 +1,167 @@
+//! Indirect addressing: each byte of a model's indirect data mirrors whichever
+//! control-table byte its indirect address points at, so that scattered items
+//! (e.g. position, current, and temperature) can be read back-to-back in one `Read`.
+
+use super::{Item, Writable, model::Entry, value::Value};
+
+/// Where a model keeps its indirect addresses and the data they point at.
+#[derive(Clone, Copy, Eq, PartialEq, defmt::Format)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Region {
+    /// `Indirect Address 1`, then the rest (two bytes each, in EEPROM).
+    pub addresses: u16,
+    /// `Indirect Data 1`, then the rest (one byte each, in RAM).
+    pub data: u16,
+    /// How many bytes can be mapped at once.
+    pub slots: u16,
+}
+
+/// X-series. Only the first block: `Indirect Address 29` onward lives elsewhere.
+pub const X: Region = Region {
+    addresses: 168,
+    data: 224,
+    slots: 28,
+};
+
+/// Dynamixel-P.
+pub const P: Region = Region {
+    addresses: 168,
+    data: 634,
+    slots: 128,
+};
+
+/// A set of items read through a region's indirect data (see `indirect!`).
+pub trait Mapping: Item + 'static {
+    const REGION: Region;
+    /// The mapped items, in order.
+    const ITEMS: &'static [Entry];
+    /// Every indirect address this mapping needs, as one item (written all at once).
+    type Addresses: Writable;
+}
+
+/// What `M::Addresses` should hold: the address of each byte of each of `M`'s items.
+#[inline]
+#[must_use]
+pub fn addresses<M: Mapping>() -> <M::Addresses as Item>::Value
+where
+    [(); <M::Addresses as Item>::BYTES as usize]:,
+{
+    let mut bytes = [0; <M::Addresses as Item>::BYTES as usize];
+    let targets = M::ITEMS
+        .iter()
+        .flat_map(|entry| entry.address..entry.address + entry.bytes);
+    for (slot, target) in bytes.as_chunks_mut::<2>().0.iter_mut().zip(targets) {
+        *slot = target.to_le_bytes();
+    }
+    Value::from_le_bytes(bytes)
+}
+
+/// Define a struct of arbitrary control-table items,
+/// one field per item (of that item's `Value` type),
+/// implementing `Item` for `$region`'s indirect data so a single `Read` fetches all of them
+/// once its `Mapping::Addresses` point there (e.g. with `Actuator::map_indirect`).
+/// Mappings that don't fit in `$region` fail to compile.
+/// Like snapshots, mappings are read-only.
+#[macro_export]
+macro_rules! indirect {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident in $region:path {
+            $first_field:ident: $first_item:ty,
+            $($field:ident: $item:ty,)*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            pub $first_field: <$first_item as $crate::control_table::Item>::Value,
+            $(pub $field: <$item as $crate::control_table::Item>::Value,)*
+        }
+
+        const _: () = assert!(
+            <$first_item as $crate::control_table::Item>::BYTES
+                $(+ <$item as $crate::control_table::Item>::BYTES)*
+                <= $region.slots,
+            concat!("`", stringify!($name), "` doesn't fit in `", stringify!($region), "`"),
+        );
+
+        impl $crate::control_table::Item for $name {
+            const ADDRESS: u16 = $region.data;
+            const BYTES: u16 = <$first_item as $crate::control_table::Item>::BYTES
+                $(+ <$item as $crate::control_table::Item>::BYTES)*;
+            const DESCRIPTION: &str = stringify!($name);
+            const ACCESS: $crate::control_table::Access = $crate::control_table::Access::ReadOnly;
+            const AREA: $crate::control_table::Area = $crate::control_table::Area::Ram;
+            type Value = Self;
+        }
+
+        // Unnameable outside this block, but reachable as `<$name as Mapping>::Addresses`:
+        const _: () = {
+            pub struct Addresses;
+
+            impl $crate::control_table::Item for Addresses {
+                const ADDRESS: u16 = $region.addresses;
+                const BYTES: u16 = 2 * <$name as $crate::control_table::Item>::BYTES;
+                const DESCRIPTION: &str = concat!("Indirect Address (", stringify!($name), ")");
+                const ACCESS: $crate::control_table::Access = $crate::control_table::Access::ReadWrite;
+                const AREA: $crate::control_table::Area = $crate::control_table::Area::Eeprom;
+                type Value = [u8; 2 * <$name as $crate::control_table::Item>::BYTES as usize];
+            }
+
+            impl $crate::control_table::Writable for Addresses {}
+
+            impl $crate::control_table::indirect::Mapping for $name {
+                const REGION: $crate::control_table::indirect::Region = $region;
+                const ITEMS: &'static [$crate::control_table::model::Entry] = &[
+                    $crate::control_table::model::Entry::of::<$first_item>(),
+                    $($crate::control_table::model::Entry::of::<$item>(),)*
+                ];
+                type Addresses = Addresses;
+            }
+        };
+
+        $crate::__fields_value! {
+            $name {
+                $first_field: $first_item,
+                $($field: $item,)*
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::control_table::{PresentCurrent, PresentPosition, PresentTemperature},
+        quickcheck_macros::quickcheck,
+    };
+
+    crate::indirect! {
+        #[derive(Clone, Copy, Debug, PartialEq, defmt::Format)]
+        struct Feedback in X {
+            present_position: PresentPosition,
+            present_current: PresentCurrent,
+            present_temperature: PresentTemperature,
+        }
+    }
+
+    #[quickcheck]
+    fn feedback_maps_each_byte(position: i32, current: i16, temperature: u8) -> bool {
+        let expected_addresses: Vec<u8> = [132, 133, 134, 135, 126, 127, 146]
+            .iter()
+            .flat_map(|&address: &u16| address.to_le_bytes())
+            .collect();
+        let mut bytes = [0; Feedback::BYTES as usize];
+        bytes[..4].copy_from_slice(&position.to_le_bytes());
+        bytes[4..6].copy_from_slice(&current.to_le_bytes());
+        bytes[6] = temperature;
+        let feedback = Feedback::from_le_bytes(bytes);
+        addresses::<Feedback>()[..] == expected_addresses[..]
+            && <Feedback as Mapping>::Addresses::ADDRESS == 168
+            && Feedback::ADDRESS == 224
+            && feedback.present_position == position
+            && feedback.present_current == current
+            && feedback.present_temperature == temperature
+            && feedback.to_le_bytes() == bytes
+    }
+}
@@ -0,0 +1,917 @@
+//! Items as laid out in the X-series; `model` records which models actually have which.
+
+pub mod ax;
+pub mod indirect;
+pub mod model;
+pub mod mx;
+pub mod mx64;
+pub mod p;
+pub mod units;
+pub mod value;
+pub mod x330;
+pub mod x540;
+pub mod xl320;
+pub mod xl430;
+pub mod xm430;
+
+use units::Quantity;
+
+#[derive(Clone, Copy, Eq, PartialEq, defmt::Format)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// EEPROM persists across power cycles but can only be written with torque off;
+/// RAM resets at power-on.
+#[derive(Clone, Copy, Eq, PartialEq, defmt::Format)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Area {
+    Eeprom,
+    Ram,
+}
+
+pub trait Item {
+    const ADDRESS: u16;
+    const BYTES: u16;
+    const DESCRIPTION: &str;
+    const ACCESS: Access;
+    const AREA: Area;
+    /// What its value measures, if it's a physical quantity (see `units`).
+    const QUANTITY: Option<Quantity> = None;
+    /// What its bytes mean (e.g. `i32` for a signed position).
+    type Value: value::Value<{ Self::BYTES as usize }>
+    where
+        [(); Self::BYTES as usize]:;
+}
+
+pub struct ModelNumber;
+impl Item for ModelNumber {
+    const ADDRESS: u16 = 0;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Model Number";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Eeprom;
+    type Value = u16;
+}
+
+pub struct ModelInformation;
+impl Item for ModelInformation {
+    const ADDRESS: u16 = 2;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Model Information";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Eeprom;
+    type Value = u32;
+}
+
+pub struct FirmwareVersion;
+impl Item for FirmwareVersion {
+    const ADDRESS: u16 = 6;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Firmware Version";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+
+pub struct Id;
+impl Item for Id {
+    const ADDRESS: u16 = 7;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "ID";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for Id {}
+
+pub struct BaudRate;
+impl Item for BaudRate {
+    const ADDRESS: u16 = 8;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Baud Rate";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::Baud;
+}
+impl Writable for BaudRate {}
+
+pub struct ReturnDelayTime;
+impl Item for ReturnDelayTime {
+    const ADDRESS: u16 = 9;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Return Delay Time";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for ReturnDelayTime {}
+
+pub struct DriveMode;
+impl Item for DriveMode {
+    const ADDRESS: u16 = 10;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Drive Mode";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::DriveMode;
+}
+impl Writable for DriveMode {}
+
+pub struct OperatingMode;
+impl Item for OperatingMode {
+    const ADDRESS: u16 = 11;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Operating Mode";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::OperatingMode;
+}
+impl Writable for OperatingMode {}
+
+pub struct SecondaryId;
+impl Item for SecondaryId {
+    const ADDRESS: u16 = 12;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Secondary ID";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for SecondaryId {}
+
+pub struct ProtocolType;
+impl Item for ProtocolType {
+    const ADDRESS: u16 = 13;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Protocol Type";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for ProtocolType {}
+
+pub struct HomingOffset;
+impl Item for HomingOffset {
+    const ADDRESS: u16 = 20;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Homing Offset";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+impl Writable for HomingOffset {}
+
+pub struct MovingThreshold;
+impl Item for MovingThreshold {
+    const ADDRESS: u16 = 24;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Moving Threshold";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = u32;
+}
+impl Writable for MovingThreshold {}
+
+pub struct TemperatureLimit;
+impl Item for TemperatureLimit {
+    const ADDRESS: u16 = 31;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Temperature Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+impl Writable for TemperatureLimit {}
+
+pub struct MaxVoltageLimit;
+impl Item for MaxVoltageLimit {
+    const ADDRESS: u16 = 32;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Max Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u16;
+}
+impl Writable for MaxVoltageLimit {}
+
+pub struct MinVoltageLimit;
+impl Item for MinVoltageLimit {
+    const ADDRESS: u16 = 34;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Min Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u16;
+}
+impl Writable for MinVoltageLimit {}
+
+pub struct PwmLimit;
+impl Item for PwmLimit {
+    const ADDRESS: u16 = 36;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "PWM Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Pwm);
+    type Value = u16;
+}
+impl Writable for PwmLimit {}
+
+pub struct CurrentLimit;
+impl Item for CurrentLimit {
+    const ADDRESS: u16 = 38;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Current Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Current);
+    type Value = u16;
+}
+impl Writable for CurrentLimit {}
+
+pub struct VelocityLimit;
+impl Item for VelocityLimit {
+    const ADDRESS: u16 = 44;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Velocity Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = u32;
+}
+impl Writable for VelocityLimit {}
+
+pub struct MaxPositionLimit;
+impl Item for MaxPositionLimit {
+    const ADDRESS: u16 = 48;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Max Position Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u32;
+}
+impl Writable for MaxPositionLimit {}
+
+pub struct MinPositionLimit;
+impl Item for MinPositionLimit {
+    const ADDRESS: u16 = 52;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Min Position Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u32;
+}
+impl Writable for MinPositionLimit {}
+
+/// Only on 540-series and Dynamixel-P models.
+pub struct ExternalPortMode1;
+impl Item for ExternalPortMode1 {
+    const ADDRESS: u16 = 56;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "External Port Mode 1";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::ExternalPortMode;
+}
+impl Writable for ExternalPortMode1 {}
+
+/// Only on 540-series and Dynamixel-P models.
+pub struct ExternalPortMode2;
+impl Item for ExternalPortMode2 {
+    const ADDRESS: u16 = 57;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "External Port Mode 2";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::ExternalPortMode;
+}
+impl Writable for ExternalPortMode2 {}
+
+/// Only on 540-series and Dynamixel-P models.
+pub struct ExternalPortMode3;
+impl Item for ExternalPortMode3 {
+    const ADDRESS: u16 = 58;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "External Port Mode 3";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::ExternalPortMode;
+}
+impl Writable for ExternalPortMode3 {}
+
+pub struct StartupConfiguration;
+impl Item for StartupConfiguration {
+    const ADDRESS: u16 = 60;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Startup Configuration";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::StartupConfiguration;
+}
+impl Writable for StartupConfiguration {}
+
+pub struct PwmSlope;
+impl Item for PwmSlope {
+    const ADDRESS: u16 = 62;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "PWM Slope";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for PwmSlope {}
+
+pub struct Shutdown;
+impl Item for Shutdown {
+    const ADDRESS: u16 = 63;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Shutdown";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = value::HardwareErrors;
+}
+impl Writable for Shutdown {}
+
+pub struct TorqueEnable;
+impl Item for TorqueEnable {
+    const ADDRESS: u16 = 64;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Torque Enable";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for TorqueEnable {}
+
+pub struct Led;
+impl Item for Led {
+    const ADDRESS: u16 = 65;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "LED";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for Led {}
+
+pub struct StatusReturnLevel;
+impl Item for StatusReturnLevel {
+    const ADDRESS: u16 = 68;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Status Return Level";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = value::StatusReturnLevel;
+}
+impl Writable for StatusReturnLevel {}
+
+pub struct RegisteredInstruction;
+impl Item for RegisteredInstruction {
+    const ADDRESS: u16 = 69;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Registered Instruction";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct HardwareErrorStatus;
+impl Item for HardwareErrorStatus {
+    const ADDRESS: u16 = 70;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Hardware Error Status";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = value::HardwareErrors;
+}
+
+pub struct VelocityIGain;
+impl Item for VelocityIGain {
+    const ADDRESS: u16 = 76;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Velocity I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for VelocityIGain {}
+
+pub struct VelocityPGain;
+impl Item for VelocityPGain {
+    const ADDRESS: u16 = 78;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Velocity P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for VelocityPGain {}
+
+pub struct PositionDGain;
+impl Item for PositionDGain {
+    const ADDRESS: u16 = 80;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position D Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionDGain {}
+
+pub struct PositionIGain;
+impl Item for PositionIGain {
+    const ADDRESS: u16 = 82;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionIGain {}
+
+pub struct PositionPGain;
+impl Item for PositionPGain {
+    const ADDRESS: u16 = 84;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionPGain {}
+
+pub struct Feedforward2ndGain;
+impl Item for Feedforward2ndGain {
+    const ADDRESS: u16 = 88;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Feedforward Second Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Feedforward2ndGain {}
+
+pub struct Feedforward1stGain;
+impl Item for Feedforward1stGain {
+    const ADDRESS: u16 = 90;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Feedforward First Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Feedforward1stGain {}
+
+pub struct BusWatchdog;
+impl Item for BusWatchdog {
+    const ADDRESS: u16 = 98;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Bus Watchdog";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for BusWatchdog {}
+
+pub struct GoalPwm;
+impl Item for GoalPwm {
+    const ADDRESS: u16 = 100;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal PWM";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Pwm);
+    type Value = i16;
+}
+impl Writable for GoalPwm {}
+
+pub struct GoalCurrent;
+impl Item for GoalCurrent {
+    const ADDRESS: u16 = 102;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal Current";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Current);
+    type Value = i16;
+}
+impl Writable for GoalCurrent {}
+
+pub struct GoalVelocity;
+impl Item for GoalVelocity {
+    const ADDRESS: u16 = 104;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Goal Velocity";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+impl Writable for GoalVelocity {}
+
+pub struct ProfileAcceleration;
+impl Item for ProfileAcceleration {
+    const ADDRESS: u16 = 108;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Profile Acceleration";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u32;
+}
+impl Writable for ProfileAcceleration {}
+
+pub struct ProfileVelocity;
+impl Item for ProfileVelocity {
+    const ADDRESS: u16 = 112;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Profile Velocity";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u32;
+}
+impl Writable for ProfileVelocity {}
+
+pub struct GoalPosition;
+impl Item for GoalPosition {
+    const ADDRESS: u16 = 116;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Goal Position";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+impl Writable for GoalPosition {}
+
+pub struct RealtimeTick;
+impl Item for RealtimeTick {
+    const ADDRESS: u16 = 120;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Real-Time Tick";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct Moving;
+impl Item for Moving {
+    const ADDRESS: u16 = 122;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct MovingStatus;
+impl Item for MovingStatus {
+    const ADDRESS: u16 = 123;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving Status";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = value::MovingStatus;
+}
+
+pub struct PresentPwm;
+impl Item for PresentPwm {
+    const ADDRESS: u16 = 124;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present PWM";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Pwm);
+    type Value = i16;
+}
+
+pub struct PresentCurrent;
+impl Item for PresentCurrent {
+    const ADDRESS: u16 = 126;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Current";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Current);
+    type Value = i16;
+}
+
+pub struct PresentVelocity;
+impl Item for PresentVelocity {
+    const ADDRESS: u16 = 128;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Present Velocity";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+
+pub struct PresentPosition;
+impl Item for PresentPosition {
+    const ADDRESS: u16 = 132;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Present Position";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+
+pub struct VelocityTrajectory;
+impl Item for VelocityTrajectory {
+    const ADDRESS: u16 = 136;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Velocity Trajectory";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+
+pub struct PositionTrajectory;
+impl Item for PositionTrajectory {
+    const ADDRESS: u16 = 140;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Position Trajectory";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+
+pub struct PresentInputVoltage;
+impl Item for PresentInputVoltage {
+    const ADDRESS: u16 = 144;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Input Voltage";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u16;
+}
+
+pub struct PresentTemperature;
+impl Item for PresentTemperature {
+    const ADDRESS: u16 = 146;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Temperature";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+
+pub struct BackupReady;
+impl Item for BackupReady {
+    const ADDRESS: u16 = 147;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Backup Ready";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+/// Analog input (0-4095), or 0 or 1 for digital input or output.
+/// Only on 540-series models.
+pub struct ExternalPortData1;
+impl Item for ExternalPortData1 {
+    const ADDRESS: u16 = 152;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 1";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData1 {}
+
+/// Analog input (0-4095), or 0 or 1 for digital input or output.
+/// Only on 540-series models.
+pub struct ExternalPortData2;
+impl Item for ExternalPortData2 {
+    const ADDRESS: u16 = 154;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 2";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData2 {}
+
+/// Analog input (0-4095), or 0 or 1 for digital input or output.
+/// Only on 540-series models.
+pub struct ExternalPortData3;
+impl Item for ExternalPortData3 {
+    const ADDRESS: u16 = 156;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 3";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData3 {}
+
+/// Implemented only for items whose `ACCESS` is `ReadWrite`,
+/// so that writing anything else fails to compile.
+pub trait Writable: Item {
+    /// Catches an `impl Writable` on a read-only item.
+    const READ_WRITE: () = assert!(
+        matches!(Self::ACCESS, Access::ReadWrite),
+        "`Writable` item isn't `ReadWrite`"
+    );
+}
+
+/// A run of back-to-back items read (or written) in one go,
+/// whose value is itself, decoded field-by-field.
+pub trait Snapshot: Item<Value = Self>
+where
+    [(); Self::BYTES as usize]:,
+{
+}
+
+/// Whether each item starts exactly where the last one ended.
+#[inline]
+#[must_use]
+pub const fn contiguous(addresses: &[u16], bytes: &[u16]) -> bool {
+    if addresses.len() != bytes.len() {
+        return false;
+    }
+    let mut i = 1;
+    while i < addresses.len() {
+        if addresses[i - 1] as u32 + bytes[i - 1] as u32 != addresses[i] as u32 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether every item is in the same area.
+#[inline]
+#[must_use]
+pub const fn same_area(areas: &[Area]) -> bool {
+    let mut i = 1;
+    while i < areas.len() {
+        if !matches!(
+            (areas[i - 1], areas[i]),
+            (Area::Eeprom, Area::Eeprom) | (Area::Ram, Area::Ram)
+        ) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `Value` for a struct with one field per item, in order (shared by `snapshot!` and `indirect!`).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fields_value {
+    (
+        $name:ident {
+            $first_field:ident: $first_item:ty,
+            $($field:ident: $item:ty,)*
+        }
+    ) => {
+        // Summed again instead of `<$name as Item>::BYTES`, which would depend on this impl:
+        impl $crate::control_table::value::Value<{ <$first_item as $crate::control_table::Item>::BYTES as usize $(+ <$item as $crate::control_table::Item>::BYTES as usize)* }> for $name {
+            #[inline]
+            fn from_le_bytes(
+                bytes: [u8; { <$first_item as $crate::control_table::Item>::BYTES as usize $(+ <$item as $crate::control_table::Item>::BYTES as usize)* }],
+            ) -> Self {
+                let mut offset = 0;
+                let mut next = |width: u16| {
+                    let field = &bytes[offset..offset + usize::from(width)];
+                    offset += usize::from(width);
+                    field
+                };
+                Self {
+                    $first_field: $crate::control_table::value::Value::from_le_bytes(
+                        next(<$first_item as $crate::control_table::Item>::BYTES).try_into().unwrap(),
+                    ),
+                    $($field: $crate::control_table::value::Value::from_le_bytes(
+                        next(<$item as $crate::control_table::Item>::BYTES).try_into().unwrap(),
+                    ),)*
+                }
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> [u8; { <$first_item as $crate::control_table::Item>::BYTES as usize $(+ <$item as $crate::control_table::Item>::BYTES as usize)* }] {
+                let mut bytes = [0; { <$first_item as $crate::control_table::Item>::BYTES as usize $(+ <$item as $crate::control_table::Item>::BYTES as usize)* }];
+                let mut offset = 0;
+                let mut next = |field: &[u8]| {
+                    bytes[offset..offset + field.len()].copy_from_slice(field);
+                    offset += field.len();
+                };
+                let () = next(&$crate::control_table::value::Value::<{ <$first_item as $crate::control_table::Item>::BYTES as usize }>::to_le_bytes(self.$first_field));
+                $(let () = next(&$crate::control_table::value::Value::<{ <$item as $crate::control_table::Item>::BYTES as usize }>::to_le_bytes(self.$field));)*
+                bytes
+            }
+        }
+    };
+}
+
+/// Define a struct spanning contiguous control-table items,
+/// one field per item (of that item's `Value` type),
+/// implementing `Item` for the whole span so a single `Read` fetches all of them.
+/// Gaps, overlaps, and spans across EEPROM and RAM fail to compile.
+/// Snapshots are read-only, even if every item in them is writable.
+#[macro_export]
+macro_rules! snapshot {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $first_field:ident: $first_item:ty,
+            $($field:ident: $item:ty,)*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            pub $first_field: <$first_item as $crate::control_table::Item>::Value,
+            $(pub $field: <$item as $crate::control_table::Item>::Value,)*
+        }
+
+        const _: () = assert!(
+            $crate::control_table::contiguous(
+                &[
+                    <$first_item as $crate::control_table::Item>::ADDRESS,
+                    $(<$item as $crate::control_table::Item>::ADDRESS,)*
+                ],
+                &[
+                    <$first_item as $crate::control_table::Item>::BYTES,
+                    $(<$item as $crate::control_table::Item>::BYTES,)*
+                ],
+            ),
+            concat!("`", stringify!($name), "` doesn't span contiguous items"),
+        );
+
+        const _: () = assert!(
+            $crate::control_table::same_area(&[
+                <$first_item as $crate::control_table::Item>::AREA,
+                $(<$item as $crate::control_table::Item>::AREA,)*
+            ]),
+            concat!("`", stringify!($name), "` spans both EEPROM and RAM"),
+        );
+
+        impl $crate::control_table::Item for $name {
+            const ADDRESS: u16 = <$first_item as $crate::control_table::Item>::ADDRESS;
+            const BYTES: u16 = <$first_item as $crate::control_table::Item>::BYTES
+                $(+ <$item as $crate::control_table::Item>::BYTES)*;
+            const DESCRIPTION: &str = stringify!($name);
+            const ACCESS: $crate::control_table::Access = $crate::control_table::Access::ReadOnly;
+            const AREA: $crate::control_table::Area = <$first_item as $crate::control_table::Item>::AREA;
+            type Value = Self;
+        }
+
+        impl $crate::control_table::Snapshot for $name {}
+
+        $crate::__fields_value! {
+            $name {
+                $first_field: $first_item,
+                $($field: $item,)*
+            }
+        }
+    };
+}
+
+snapshot! {
+    /// Everything a control loop usually polls, from `PresentPwm` through `PresentTemperature`.
+    #[derive(Clone, Copy, defmt::Format)]
+    #[cfg_attr(test, derive(Debug, PartialEq))]
+    pub struct ActuatorState {
+        present_pwm: PresentPwm,
+        present_current: PresentCurrent,
+        present_velocity: PresentVelocity,
+        present_position: PresentPosition,
+        velocity_trajectory: VelocityTrajectory,
+        position_trajectory: PositionTrajectory,
+        present_input_voltage: PresentInputVoltage,
+        present_temperature: PresentTemperature,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, quickcheck_macros::quickcheck, value::Value};
+
+    #[quickcheck]
+    fn actuator_state_fields_in_order(position: i32, temperature: u8) -> bool {
+        let mut bytes = [0; ActuatorState::BYTES as usize];
+        let offset = (PresentPosition::ADDRESS - ActuatorState::ADDRESS) as usize;
+        bytes[offset..offset + 4].copy_from_slice(&position.to_le_bytes());
+        *bytes.last_mut().unwrap() = temperature;
+        let state = ActuatorState::from_le_bytes(bytes);
+        state.present_position == position
+            && state.present_temperature == temperature
+            && state.to_le_bytes() == bytes
+    }
+}
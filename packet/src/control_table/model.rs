@@ -0,0 +1,246 @@
+//! Which items each model actually has, keyed by the model number it reports to a ping.
+
+use super::{Access, Area, Item, units::Quantity};
+
+/// One item's place in a model's control table,
+/// copied from its `Item` impl so that it can be inspected at runtime.
+#[derive(Clone, Copy, defmt::Format)]
+pub struct Entry {
+    pub description: &'static str,
+    pub address: u16,
+    pub bytes: u16,
+    pub access: Access,
+    pub area: Area,
+    pub quantity: Option<Quantity>,
+}
+
+impl Entry {
+    #[inline(always)]
+    #[must_use]
+    pub const fn of<I: Item>() -> Self {
+        Self {
+            description: I::DESCRIPTION,
+            address: I::ADDRESS,
+            bytes: I::BYTES,
+            access: I::ACCESS,
+            area: I::AREA,
+            quantity: I::QUANTITY,
+        }
+    }
+}
+
+/// Build a `&[Entry]` from a comma-separated list of `Item`s.
+macro_rules! entries {
+    ($($item:ty),* $(,)?) => {
+        &[$($crate::control_table::model::Entry::of::<$item>()),*]
+    };
+}
+pub(crate) use entries;
+
+pub struct Table {
+    pub name: &'static str,
+    pub model_numbers: &'static [u16],
+    /// In order of address.
+    pub items: &'static [Entry],
+    /// Where indirect addresses and data live, if this model has them.
+    pub indirect: Option<super::indirect::Region>,
+}
+
+impl Table {
+    /// Whether `bytes` bytes starting at `address` are made up of whole items,
+    /// so that e.g. a snapshot spanning several items can be checked at once.
+    #[inline]
+    #[must_use]
+    pub fn covers(&self, address: u16, bytes: u16) -> bool {
+        let end = u32::from(address) + u32::from(bytes);
+        let mut at = u32::from(address);
+        while at < end {
+            let Some(entry) = self
+                .items
+                .iter()
+                .find(|entry| u32::from(entry.address) == at)
+            else {
+                return false;
+            };
+            at += u32::from(entry.bytes);
+        }
+        at == end
+    }
+
+    /// Whether this model has `I`: the item itself if it's one of ours
+    /// (not just another item at the same address, e.g. XL430's `PresentLoad` for `PresentCurrent`),
+    /// or whole items underneath it if it spans several (e.g. a snapshot).
+    #[inline]
+    #[must_use]
+    pub fn has<I: Item>(&self) -> bool {
+        match self.at(I::ADDRESS) {
+            Some(entry) if entry.bytes == I::BYTES => entry.description == I::DESCRIPTION,
+            _ => self.covers(I::ADDRESS, I::BYTES),
+        }
+    }
+
+    /// The item named `description`, if this model has it.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, description: &str) -> Option<&'static Entry> {
+        self.items
+            .iter()
+            .find(|entry| entry.description == description)
+    }
+
+    /// The item starting at `address`, if this model has one there.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, address: u16) -> Option<&'static Entry> {
+        self.items.iter().find(|entry| entry.address == address)
+    }
+}
+
+impl defmt::Format for Table {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", self.name)
+    }
+}
+
+/// Every item in `control_table`, for models we don't recognize,
+/// so that nothing is refused that worked before tables were per-model.
+/// Also what to name addresses by when the model isn't known (e.g. when sniffing a bus).
+pub const UNRECOGNIZED: Table = Table {
+    name: "Unrecognized model",
+    model_numbers: &[],
+    items: entries![
+        super::ModelNumber,
+        super::ModelInformation,
+        super::FirmwareVersion,
+        super::Id,
+        super::BaudRate,
+        super::ReturnDelayTime,
+        super::DriveMode,
+        super::OperatingMode,
+        super::SecondaryId,
+        super::ProtocolType,
+        super::HomingOffset,
+        super::MovingThreshold,
+        super::TemperatureLimit,
+        super::MaxVoltageLimit,
+        super::MinVoltageLimit,
+        super::PwmLimit,
+        super::CurrentLimit,
+        super::VelocityLimit,
+        super::MaxPositionLimit,
+        super::MinPositionLimit,
+        super::ExternalPortMode1,
+        super::ExternalPortMode2,
+        super::ExternalPortMode3,
+        super::StartupConfiguration,
+        super::PwmSlope,
+        super::Shutdown,
+        super::TorqueEnable,
+        super::Led,
+        super::StatusReturnLevel,
+        super::RegisteredInstruction,
+        super::HardwareErrorStatus,
+        super::VelocityIGain,
+        super::VelocityPGain,
+        super::PositionDGain,
+        super::PositionIGain,
+        super::PositionPGain,
+        super::Feedforward2ndGain,
+        super::Feedforward1stGain,
+        super::BusWatchdog,
+        super::GoalPwm,
+        super::GoalCurrent,
+        super::GoalVelocity,
+        super::ProfileAcceleration,
+        super::ProfileVelocity,
+        super::GoalPosition,
+        super::RealtimeTick,
+        super::Moving,
+        super::MovingStatus,
+        super::PresentPwm,
+        super::PresentCurrent,
+        super::PresentVelocity,
+        super::PresentPosition,
+        super::VelocityTrajectory,
+        super::PositionTrajectory,
+        super::PresentInputVoltage,
+        super::PresentTemperature,
+        super::BackupReady,
+        super::ExternalPortData1,
+        super::ExternalPortData2,
+        super::ExternalPortData3,
+    ],
+    indirect: Some(super::indirect::X),
+};
+
+pub const TABLES: &[&Table] = &[
+    &super::xm430::TABLE,
+    &super::x540::TABLE,
+    &super::xl430::TABLE,
+    &super::x330::TABLE,
+    &super::xl320::TABLE,
+    &super::p::TABLE,
+    &super::ax::TABLE,
+    &super::mx::TABLE,
+    &super::mx64::TABLE,
+];
+
+/// Whether each item ends at or before the next one starts.
+#[inline]
+const fn sorted(items: &[Entry]) -> bool {
+    let mut i = 1;
+    while i < items.len() {
+        if items[i - 1].address as u32 + items[i - 1].bytes as u32 > items[i].address as u32 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const _: () = {
+    assert!(sorted(UNRECOGNIZED.items), "Unsorted or overlapping items");
+    let mut i = 0;
+    while i < TABLES.len() {
+        assert!(sorted(TABLES[i].items), "Unsorted or overlapping items");
+        i += 1;
+    }
+};
+
+/// The table for this model number, or `None` if we don't know it.
+#[inline]
+#[must_use]
+pub fn lookup(model_number: u16) -> Option<&'static Table> {
+    TABLES
+        .iter()
+        .copied()
+        .find(|table| table.model_numbers.contains(&model_number))
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::control_table::{PresentCurrent, xl430},
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn lookups_by_address_and_name_agree(address: u16) -> bool {
+        TABLES.iter().copied().chain([&UNRECOGNIZED]).all(|table| {
+            table.at(address).is_none_or(|entry| {
+                entry.address == address
+                    && table
+                        .get(entry.description)
+                        .is_some_and(|named| named.address == address)
+            })
+        })
+    }
+
+    #[test]
+    fn same_address_and_width_is_not_the_same_item() {
+        assert!(xl430::TABLE.has::<xl430::PresentLoad>());
+        assert!(!xl430::TABLE.has::<PresentCurrent>());
+    }
+}
@@ -0,0 +1,128 @@
+//! MX-12W and MX-28 on Protocol 1.0: the AX-series layout with PID gains (instead of compliance),
+//! multi-turn, and an acceleration limit.
+
+use {
+    super::{Access, Area, Item, Writable, ax, units::Quantity, xl320},
+    crate::control_table::model::{Table, entries},
+};
+
+pub struct MultiTurnOffset;
+impl Item for MultiTurnOffset {
+    const ADDRESS: u16 = 20;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Multi-Turn Offset";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i16;
+}
+impl Writable for MultiTurnOffset {}
+
+pub struct ResolutionDivider;
+impl Item for ResolutionDivider {
+    const ADDRESS: u16 = 22;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Resolution Divider";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for ResolutionDivider {}
+
+pub struct DGain;
+impl Item for DGain {
+    const ADDRESS: u16 = 26;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "D Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for DGain {}
+
+pub struct IGain;
+impl Item for IGain {
+    const ADDRESS: u16 = 27;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for IGain {}
+
+pub struct PGain;
+impl Item for PGain {
+    const ADDRESS: u16 = 28;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for PGain {}
+
+pub struct RealtimeTick;
+impl Item for RealtimeTick {
+    const ADDRESS: u16 = 50;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Real-Time Tick";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+/// In units of 8.583°/s², or 0 for no limit.
+pub struct GoalAcceleration;
+impl Item for GoalAcceleration {
+    const ADDRESS: u16 = 73;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Goal Acceleration";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for GoalAcceleration {}
+
+pub const TABLE: Table = Table {
+    name: "MX-12W / MX-28 (Protocol 1.0)",
+    model_numbers: &[29, 360],
+    items: entries![
+        xl320::ModelNumber,
+        xl320::FirmwareVersion,
+        xl320::Id,
+        xl320::BaudRate,
+        xl320::ReturnDelayTime,
+        xl320::CwAngleLimit,
+        xl320::CcwAngleLimit,
+        ax::TemperatureLimit,
+        ax::MinVoltageLimit,
+        ax::MaxVoltageLimit,
+        ax::MaxTorque,
+        ax::StatusReturnLevel,
+        ax::AlarmLed,
+        xl320::Shutdown,
+        MultiTurnOffset,
+        ResolutionDivider,
+        xl320::TorqueEnable,
+        xl320::Led,
+        DGain,
+        IGain,
+        PGain,
+        xl320::GoalPosition,
+        xl320::MovingSpeed,
+        ax::TorqueLimit,
+        ax::PresentPosition,
+        ax::PresentSpeed,
+        ax::PresentLoad,
+        ax::PresentVoltage,
+        ax::PresentTemperature,
+        ax::RegisteredInstruction,
+        ax::Moving,
+        ax::Lock,
+        ax::Punch,
+        RealtimeTick,
+        GoalAcceleration,
+    ],
+    indirect: None,
+};
@@ -0,0 +1,85 @@
+//! MX-64 and MX-106 on Protocol 1.0: the MX-28's table plus current sensing and torque control.
+
+use {
+    super::{Access, Area, Item, Writable, ax, mx, xl320},
+    crate::control_table::model::{Table, entries},
+};
+
+/// Centered on 2048, in units of 4.5 mA.
+pub struct Current;
+impl Item for Current {
+    const ADDRESS: u16 = 68;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Current";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct TorqueControlModeEnable;
+impl Item for TorqueControlModeEnable {
+    const ADDRESS: u16 = 70;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Torque Control Mode Enable";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for TorqueControlModeEnable {}
+
+pub struct GoalTorque;
+impl Item for GoalTorque {
+    const ADDRESS: u16 = 71;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal Torque";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for GoalTorque {}
+
+pub const TABLE: Table = Table {
+    name: "MX-64 / MX-106 (Protocol 1.0)",
+    model_numbers: &[310, 320],
+    items: entries![
+        xl320::ModelNumber,
+        xl320::FirmwareVersion,
+        xl320::Id,
+        xl320::BaudRate,
+        xl320::ReturnDelayTime,
+        xl320::CwAngleLimit,
+        xl320::CcwAngleLimit,
+        ax::TemperatureLimit,
+        ax::MinVoltageLimit,
+        ax::MaxVoltageLimit,
+        ax::MaxTorque,
+        ax::StatusReturnLevel,
+        ax::AlarmLed,
+        xl320::Shutdown,
+        mx::MultiTurnOffset,
+        mx::ResolutionDivider,
+        xl320::TorqueEnable,
+        xl320::Led,
+        mx::DGain,
+        mx::IGain,
+        mx::PGain,
+        xl320::GoalPosition,
+        xl320::MovingSpeed,
+        ax::TorqueLimit,
+        ax::PresentPosition,
+        ax::PresentSpeed,
+        ax::PresentLoad,
+        ax::PresentVoltage,
+        ax::PresentTemperature,
+        ax::RegisteredInstruction,
+        ax::Moving,
+        ax::Lock,
+        ax::Punch,
+        mx::RealtimeTick,
+        Current,
+        TorqueControlModeEnable,
+        GoalTorque,
+        mx::GoalAcceleration,
+    ],
+    indirect: None,
+};
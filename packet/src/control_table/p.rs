@@ -0,0 +1,527 @@
+//! Dynamixel-P (PH54, PH42, PM54, PM42): the same EEPROM as the X-series (plus an acceleration limit),
+//! but RAM starts at address 512, so goals and present values need 16-bit addresses.
+
+use {
+    super::{Access, Area, Item, Writable, units::Quantity},
+    crate::control_table::model::{Table, entries},
+};
+
+pub struct AccelerationLimit;
+impl Item for AccelerationLimit {
+    const ADDRESS: u16 = 40;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Acceleration Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u32;
+}
+impl Writable for AccelerationLimit {}
+
+/// Ports 1-3 use the X-series `ExternalPortMode*`; only the Dynamixel-P has a fourth.
+pub struct ExternalPortMode4;
+impl Item for ExternalPortMode4 {
+    const ADDRESS: u16 = 59;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "External Port Mode 4";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = super::value::ExternalPortMode;
+}
+impl Writable for ExternalPortMode4 {}
+
+pub struct TorqueEnable;
+impl Item for TorqueEnable {
+    const ADDRESS: u16 = 512;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Torque Enable";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for TorqueEnable {}
+
+pub struct LedRed;
+impl Item for LedRed {
+    const ADDRESS: u16 = 513;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "LED Red";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for LedRed {}
+
+pub struct LedGreen;
+impl Item for LedGreen {
+    const ADDRESS: u16 = 514;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "LED Green";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for LedGreen {}
+
+pub struct LedBlue;
+impl Item for LedBlue {
+    const ADDRESS: u16 = 515;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "LED Blue";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for LedBlue {}
+
+pub struct StatusReturnLevel;
+impl Item for StatusReturnLevel {
+    const ADDRESS: u16 = 516;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Status Return Level";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = super::value::StatusReturnLevel;
+}
+impl Writable for StatusReturnLevel {}
+
+pub struct RegisteredInstruction;
+impl Item for RegisteredInstruction {
+    const ADDRESS: u16 = 517;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Registered Instruction";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct HardwareErrorStatus;
+impl Item for HardwareErrorStatus {
+    const ADDRESS: u16 = 518;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Hardware Error Status";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = super::value::HardwareErrors;
+}
+
+pub struct VelocityIGain;
+impl Item for VelocityIGain {
+    const ADDRESS: u16 = 524;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Velocity I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for VelocityIGain {}
+
+pub struct VelocityPGain;
+impl Item for VelocityPGain {
+    const ADDRESS: u16 = 526;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Velocity P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for VelocityPGain {}
+
+pub struct PositionDGain;
+impl Item for PositionDGain {
+    const ADDRESS: u16 = 528;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position D Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionDGain {}
+
+pub struct PositionIGain;
+impl Item for PositionIGain {
+    const ADDRESS: u16 = 530;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionIGain {}
+
+pub struct PositionPGain;
+impl Item for PositionPGain {
+    const ADDRESS: u16 = 532;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Position P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for PositionPGain {}
+
+pub struct Feedforward2ndGain;
+impl Item for Feedforward2ndGain {
+    const ADDRESS: u16 = 536;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Feedforward Second Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Feedforward2ndGain {}
+
+pub struct Feedforward1stGain;
+impl Item for Feedforward1stGain {
+    const ADDRESS: u16 = 538;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Feedforward First Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Feedforward1stGain {}
+
+pub struct BusWatchdog;
+impl Item for BusWatchdog {
+    const ADDRESS: u16 = 546;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Bus Watchdog";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for BusWatchdog {}
+
+pub struct GoalPwm;
+impl Item for GoalPwm {
+    const ADDRESS: u16 = 548;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal PWM";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Pwm);
+    type Value = i16;
+}
+impl Writable for GoalPwm {}
+
+pub struct GoalCurrent;
+impl Item for GoalCurrent {
+    const ADDRESS: u16 = 550;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal Current";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Current);
+    type Value = i16;
+}
+impl Writable for GoalCurrent {}
+
+pub struct GoalVelocity;
+impl Item for GoalVelocity {
+    const ADDRESS: u16 = 552;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Goal Velocity";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+impl Writable for GoalVelocity {}
+
+pub struct ProfileAcceleration;
+impl Item for ProfileAcceleration {
+    const ADDRESS: u16 = 556;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Profile Acceleration";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u32;
+}
+impl Writable for ProfileAcceleration {}
+
+pub struct ProfileVelocity;
+impl Item for ProfileVelocity {
+    const ADDRESS: u16 = 560;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Profile Velocity";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u32;
+}
+impl Writable for ProfileVelocity {}
+
+pub struct GoalPosition;
+impl Item for GoalPosition {
+    const ADDRESS: u16 = 564;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Goal Position";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+impl Writable for GoalPosition {}
+
+pub struct RealtimeTick;
+impl Item for RealtimeTick {
+    const ADDRESS: u16 = 568;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Real-Time Tick";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct Moving;
+impl Item for Moving {
+    const ADDRESS: u16 = 570;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct MovingStatus;
+impl Item for MovingStatus {
+    const ADDRESS: u16 = 571;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving Status";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = super::value::MovingStatus;
+}
+
+pub struct PresentPwm;
+impl Item for PresentPwm {
+    const ADDRESS: u16 = 572;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present PWM";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Pwm);
+    type Value = i16;
+}
+
+pub struct PresentCurrent;
+impl Item for PresentCurrent {
+    const ADDRESS: u16 = 574;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Current";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Current);
+    type Value = i16;
+}
+
+pub struct PresentVelocity;
+impl Item for PresentVelocity {
+    const ADDRESS: u16 = 576;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Present Velocity";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+
+pub struct PresentPosition;
+impl Item for PresentPosition {
+    const ADDRESS: u16 = 580;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Present Position";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+
+pub struct VelocityTrajectory;
+impl Item for VelocityTrajectory {
+    const ADDRESS: u16 = 584;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Velocity Trajectory";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Velocity);
+    type Value = i32;
+}
+
+pub struct PositionTrajectory;
+impl Item for PositionTrajectory {
+    const ADDRESS: u16 = 588;
+    const BYTES: u16 = 4;
+    const DESCRIPTION: &str = "Position Trajectory";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = i32;
+}
+
+pub struct PresentInputVoltage;
+impl Item for PresentInputVoltage {
+    const ADDRESS: u16 = 592;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Input Voltage";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u16;
+}
+
+pub struct PresentTemperature;
+impl Item for PresentTemperature {
+    const ADDRESS: u16 = 594;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Temperature";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+
+pub struct ExternalPortData1;
+impl Item for ExternalPortData1 {
+    const ADDRESS: u16 = 600;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 1";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData1 {}
+
+pub struct ExternalPortData2;
+impl Item for ExternalPortData2 {
+    const ADDRESS: u16 = 602;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 2";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData2 {}
+
+pub struct ExternalPortData3;
+impl Item for ExternalPortData3 {
+    const ADDRESS: u16 = 604;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 3";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData3 {}
+
+pub struct ExternalPortData4;
+impl Item for ExternalPortData4 {
+    const ADDRESS: u16 = 606;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "External Port Data 4";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for ExternalPortData4 {}
+
+crate::snapshot! {
+    /// Everything a control loop usually polls, from `PresentPwm` through `PresentTemperature`.
+    #[derive(Clone, Copy, defmt::Format)]
+    pub struct ActuatorState {
+        present_pwm: PresentPwm,
+        present_current: PresentCurrent,
+        present_velocity: PresentVelocity,
+        present_position: PresentPosition,
+        velocity_trajectory: VelocityTrajectory,
+        position_trajectory: PositionTrajectory,
+        present_input_voltage: PresentInputVoltage,
+        present_temperature: PresentTemperature,
+    }
+}
+
+/// The same fields, so callers can treat either family's state alike.
+impl From<ActuatorState> for super::ActuatorState {
+    #[inline]
+    fn from(state: ActuatorState) -> Self {
+        Self {
+            present_pwm: state.present_pwm,
+            present_current: state.present_current,
+            present_velocity: state.present_velocity,
+            present_position: state.present_position,
+            velocity_trajectory: state.velocity_trajectory,
+            position_trajectory: state.position_trajectory,
+            present_input_voltage: state.present_input_voltage,
+            present_temperature: state.present_temperature,
+        }
+    }
+}
+
+pub const TABLE: Table = Table {
+    name: "Dynamixel-P",
+    model_numbers: &[2000, 2010, 2020, 2100, 2110, 2120],
+    items: entries![
+        super::ModelNumber,
+        super::ModelInformation,
+        super::FirmwareVersion,
+        super::Id,
+        super::BaudRate,
+        super::ReturnDelayTime,
+        super::DriveMode,
+        super::OperatingMode,
+        super::SecondaryId,
+        super::ProtocolType,
+        super::HomingOffset,
+        super::MovingThreshold,
+        super::TemperatureLimit,
+        super::MaxVoltageLimit,
+        super::MinVoltageLimit,
+        super::PwmLimit,
+        super::CurrentLimit,
+        AccelerationLimit,
+        super::VelocityLimit,
+        super::MaxPositionLimit,
+        super::MinPositionLimit,
+        super::ExternalPortMode1,
+        super::ExternalPortMode2,
+        super::ExternalPortMode3,
+        ExternalPortMode4,
+        super::Shutdown,
+        TorqueEnable,
+        LedRed,
+        LedGreen,
+        LedBlue,
+        StatusReturnLevel,
+        RegisteredInstruction,
+        HardwareErrorStatus,
+        VelocityIGain,
+        VelocityPGain,
+        PositionDGain,
+        PositionIGain,
+        PositionPGain,
+        Feedforward2ndGain,
+        Feedforward1stGain,
+        BusWatchdog,
+        GoalPwm,
+        GoalCurrent,
+        GoalVelocity,
+        ProfileAcceleration,
+        ProfileVelocity,
+        GoalPosition,
+        RealtimeTick,
+        Moving,
+        MovingStatus,
+        PresentPwm,
+        PresentCurrent,
+        PresentVelocity,
+        PresentPosition,
+        VelocityTrajectory,
+        PositionTrajectory,
+        PresentInputVoltage,
+        PresentTemperature,
+        ExternalPortData1,
+        ExternalPortData2,
+        ExternalPortData3,
+        ExternalPortData4,
+    ],
+    indirect: Some(super::indirect::P),
+};
@@ -0,0 +1,221 @@
+//! Physical units for raw values, which differ between (and sometimes within) model families,
+//! so they're keyed by model number instead of by `model::Table`.
+//! Voltage (0.1 V) and temperature (1 °C) are the same everywhere.
+
+use core::f32::consts::TAU;
+
+/// What an item's raw value measures, i.e. which of `Units`' conversions applies to it.
+#[derive(Clone, Copy, Eq, PartialEq, defmt::Format)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Quantity {
+    Position,
+    Velocity,
+    Current,
+    Pwm,
+    Voltage,
+    Temperature,
+}
+
+impl Quantity {
+    /// The unit `Units::to_si` converts to.
+    #[inline]
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Position => "rad",
+            Self::Velocity => "rad/s",
+            Self::Current => "mA",
+            Self::Pwm => "%",
+            Self::Voltage => "V",
+            Self::Temperature => "°C",
+        }
+    }
+}
+
+/// Scale factors for one model. Conversions to raw values round to the nearest unit
+/// and saturate at the edges of the raw type.
+#[derive(Clone, Copy, defmt::Format)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Units {
+    /// Position ticks per full revolution.
+    pub ticks_per_revolution: f32,
+    /// Revolutions per minute per unit of velocity.
+    pub rpm_per_unit: f32,
+    /// Milliamps per unit of current, or `None` without current sensing
+    /// (e.g. XL430, which reports load instead).
+    pub milliamps_per_unit: Option<f32>,
+    /// PWM units at a 100% duty cycle.
+    pub full_pwm: f32,
+}
+
+const X: Units = Units {
+    ticks_per_revolution: 4_096.,
+    rpm_per_unit: 0.229,
+    milliamps_per_unit: Some(2.69),
+    full_pwm: 885.,
+};
+
+const P: Units = Units {
+    ticks_per_revolution: 501_923. * 2.,
+    rpm_per_unit: 0.01,
+    milliamps_per_unit: Some(1.),
+    full_pwm: 2_009.,
+};
+
+/// Units for this model number, or `None` if we don't know them.
+#[inline]
+#[must_use]
+pub const fn of(model_number: u16) -> Option<Units> {
+    Some(match model_number {
+        // XM430-W, XH430-W, XW430, and the 540-series:
+        1000..=1030 | 1100 | 1110 | 1120 | 1130 | 1140 | 1150 | 1170 | 1180 | 1270 | 1280 => X,
+        // XH430-V:
+        1040 | 1050 => Units {
+            milliamps_per_unit: Some(1.34),
+            ..X
+        },
+        // XL430, 2XL430, XC430, and 2XC430:
+        1060 | 1070 | 1080 | 1090 | 1160 => Units {
+            milliamps_per_unit: None,
+            ..X
+        },
+        // XL330 and XC330:
+        1190..=1240 => Units {
+            milliamps_per_unit: Some(1.),
+            ..X
+        },
+        // PH42-020:
+        2000 => Units {
+            ticks_per_revolution: 303_751. * 2.,
+            ..P
+        },
+        // PH54-100 and PH54-200:
+        2010 | 2020 => P,
+        // PM42-010:
+        2100 => Units {
+            ticks_per_revolution: 263_187. * 2.,
+            ..P
+        },
+        // PM54-040 and PM54-060:
+        2110 | 2120 => Units {
+            ticks_per_revolution: 502_834. * 2.,
+            ..P
+        },
+        _ => return None,
+    })
+}
+
+/// Round to the nearest integer without `std` (`as` then saturates).
+#[inline(always)]
+fn round(x: f32) -> f32 {
+    x + 0.5_f32.copysign(x)
+}
+
+impl Units {
+    #[inline]
+    #[must_use]
+    pub fn ticks_to_rad(self, ticks: i32) -> f32 {
+        ticks as f32 * TAU / self.ticks_per_revolution
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rad_to_ticks(self, rad: f32) -> i32 {
+        round(rad * self.ticks_per_revolution / TAU) as i32
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ticks_to_deg(self, ticks: i32) -> f32 {
+        ticks as f32 * 360. / self.ticks_per_revolution
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn deg_to_ticks(self, deg: f32) -> i32 {
+        round(deg * self.ticks_per_revolution / 360.) as i32
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn velocity_to_rad_s(self, velocity: i32) -> f32 {
+        velocity as f32 * self.rpm_per_unit * TAU / 60.
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rad_s_to_velocity(self, rad_s: f32) -> i32 {
+        round(rad_s * 60. / (TAU * self.rpm_per_unit)) as i32
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn current_to_ma(self, current: i16) -> Option<f32> {
+        Some(f32::from(current) * self.milliamps_per_unit?)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ma_to_current(self, ma: f32) -> Option<i16> {
+        Some(round(ma / self.milliamps_per_unit?) as i16)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn pwm_to_percent(self, pwm: i16) -> f32 {
+        f32::from(pwm) * 100. / self.full_pwm
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn percent_to_pwm(self, percent: f32) -> i16 {
+        round(percent * self.full_pwm / 100.) as i16
+    }
+
+    /// Convert any item's raw value (widened to `i32`) knowing only its `Quantity`,
+    /// e.g. for a generic dump. `None` if this model can't measure it.
+    #[inline]
+    #[must_use]
+    pub fn to_si(self, quantity: Quantity, raw: i32) -> Option<f32> {
+        Some(match quantity {
+            Quantity::Position => self.ticks_to_rad(raw),
+            Quantity::Velocity => self.velocity_to_rad_s(raw),
+            Quantity::Current => raw as f32 * self.milliamps_per_unit?,
+            Quantity::Pwm => raw as f32 * 100. / self.full_pwm,
+            Quantity::Voltage => raw as f32 * 0.1,
+            Quantity::Temperature => raw as f32,
+        })
+    }
+}
+
+#[inline]
+#[must_use]
+pub fn voltage_to_v(voltage: u16) -> f32 {
+    f32::from(voltage) * 0.1
+}
+
+#[inline]
+#[must_use]
+pub fn temperature_to_c(temperature: u8) -> f32 {
+    f32::from(temperature)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, quickcheck_macros::quickcheck};
+
+    #[quickcheck]
+    fn raw_values_roundtrip(ticks: i16, velocity: i16, pwm: i8) -> bool {
+        let (ticks, velocity, pwm) = (i32::from(ticks), i32::from(velocity), i16::from(pwm));
+        crate::control_table::model::TABLES
+            .iter()
+            .flat_map(|table| table.model_numbers)
+            .filter_map(|&model_number| of(model_number))
+            .all(|units| {
+                units.rad_to_ticks(units.ticks_to_rad(ticks)) == ticks
+                    && units.deg_to_ticks(units.ticks_to_deg(ticks)) == ticks
+                    && units.rad_s_to_velocity(units.velocity_to_rad_s(velocity)) == velocity
+                    && units.percent_to_pwm(units.pwm_to_percent(pwm)) == pwm
+            })
+    }
+}
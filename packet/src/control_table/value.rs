@@ -0,0 +1,293 @@
+//! What the bytes of each item mean: signed integers, flags, and enumerations
+//! instead of whatever unsigned integer happens to be the right width.
+
+/// Little-endian encoding of an item's value in exactly `BYTES` bytes.
+pub trait Value<const BYTES: usize>: Copy + defmt::Format {
+    fn from_le_bytes(bytes: [u8; BYTES]) -> Self;
+    fn to_le_bytes(self) -> [u8; BYTES];
+}
+
+macro_rules! int_value {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl Value<{ size_of::<$int>() }> for $int {
+                #[inline(always)]
+                fn from_le_bytes(bytes: [u8; size_of::<$int>()]) -> Self {
+                    <$int>::from_le_bytes(bytes)
+                }
+
+                #[inline(always)]
+                fn to_le_bytes(self) -> [u8; size_of::<$int>()] {
+                    <$int>::to_le_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+int_value!(u8, u16, u32, i8, i16, i32);
+
+/// Anything nonzero reads as `true`; `true` writes as 1.
+impl Value<1> for bool {
+    #[inline(always)]
+    fn from_le_bytes([byte]: [u8; 1]) -> Self {
+        byte != 0
+    }
+
+    #[inline(always)]
+    fn to_le_bytes(self) -> [u8; 1] {
+        [u8::from(self)]
+    }
+}
+
+/// Raw bytes, for items with no more meaningful type.
+impl<const BYTES: usize> Value<BYTES> for [u8; BYTES] {
+    #[inline(always)]
+    fn from_le_bytes(bytes: [u8; BYTES]) -> Self {
+        bytes
+    }
+
+    #[inline(always)]
+    fn to_le_bytes(self) -> [u8; BYTES] {
+        self
+    }
+}
+
+/// Define a one-byte enumeration, plus an `Other` variant
+/// so that bytes we don't recognize still round-trip instead of failing to decode.
+macro_rules! enumeration {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $byte:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Eq, PartialEq, defmt::Format)]
+        #[cfg_attr(test, derive(Debug))]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Not documented for any model we know.
+            Other(u8),
+        }
+
+        impl Value<1> for $name {
+            #[inline]
+            fn from_le_bytes([byte]: [u8; 1]) -> Self {
+                match byte {
+                    $($byte => Self::$variant,)*
+                    other => Self::Other(other),
+                }
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> [u8; 1] {
+                match self {
+                    $(Self::$variant => [$byte],)*
+                    Self::Other(byte) => [byte],
+                }
+            }
+        }
+    };
+}
+
+/// Implement `Value` and `defmt::Format` for a one-byte `bitflags` type,
+/// keeping any bits we don't have names for.
+macro_rules! flags_value {
+    ($name:ident) => {
+        impl Value<1> for $name {
+            #[inline(always)]
+            fn from_le_bytes([byte]: [u8; 1]) -> Self {
+                Self::from_bits_retain(byte)
+            }
+
+            #[inline(always)]
+            fn to_le_bytes(self) -> [u8; 1] {
+                [self.bits()]
+            }
+        }
+
+        impl defmt::Format for $name {
+            #[inline]
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, "{}({=u8:#010b})", stringify!($name), self.bits())
+            }
+        }
+    };
+}
+
+enumeration! {
+    pub enum Baud {
+        Baud9600 = 0,
+        Baud57600 = 1,
+        Baud115200 = 2,
+        Baud1000000 = 3,
+        Baud2000000 = 4,
+        Baud3000000 = 5,
+        Baud4000000 = 6,
+        Baud4500000 = 7,
+    }
+}
+
+impl Baud {
+    /// Bits per second, or `None` for `Other`.
+    #[inline]
+    #[must_use]
+    pub const fn bits_per_second(self) -> Option<u32> {
+        match self {
+            Self::Baud9600 => Some(9_600),
+            Self::Baud57600 => Some(57_600),
+            Self::Baud115200 => Some(115_200),
+            Self::Baud1000000 => Some(1_000_000),
+            Self::Baud2000000 => Some(2_000_000),
+            Self::Baud3000000 => Some(3_000_000),
+            Self::Baud4000000 => Some(4_000_000),
+            Self::Baud4500000 => Some(4_500_000),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+enumeration! {
+    pub enum OperatingMode {
+        Current = 0,
+        Velocity = 1,
+        Position = 3,
+        ExtendedPosition = 4,
+        CurrentBasedPosition = 5,
+        Pwm = 16,
+    }
+}
+
+enumeration! {
+    /// What an external port (`ExternalPortData*`) does.
+    pub enum ExternalPortMode {
+        AnalogInput = 0,
+        DigitalOutput = 1,
+        DigitalInputPullDown = 2,
+        DigitalInputPullUp = 3,
+    }
+}
+
+enumeration! {
+    /// Which instructions get a status packet back (pings always do).
+    pub enum StatusReturnLevel {
+        PingOnly = 0,
+        PingAndRead = 1,
+        All = 2,
+    }
+}
+
+impl StatusReturnLevel {
+    /// Whether an actuator at this level answers the instruction whose byte is `instruction`
+    /// (e.g. `<Insn as Instruction>::BYTE`), counting synchronized and bulk reads as reads.
+    /// Levels we don't recognize are assumed to answer everything.
+    #[inline]
+    #[must_use]
+    pub fn answers(self, instruction: u8) -> bool {
+        use crate::packet::Instruction;
+        match self {
+            Self::PingOnly => {
+                matches!(Instruction::from_repr(instruction), Some(Instruction::Ping))
+            }
+            Self::PingAndRead => matches!(
+                Instruction::from_repr(instruction),
+                Some(
+                    Instruction::Ping
+                        | Instruction::Read
+                        | Instruction::SyncRead
+                        | Instruction::FastSyncRead
+                        | Instruction::BulkRead
+                        | Instruction::FastBulkRead
+                )
+            ),
+            Self::All | Self::Other(_) => true,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(test, derive(Debug))]
+    pub struct DriveMode: u8 {
+        /// Count positive positions and velocities clockwise instead of counterclockwise.
+        const REVERSE = 0x01;
+        /// Profiles set durations (ms) instead of velocity and acceleration.
+        const TIME_BASED_PROFILE = 0x04;
+        /// Turn torque on when a goal is written, even if it was off.
+        const TORQUE_ON_BY_GOAL_UPDATE = 0x08;
+    }
+}
+flags_value!(DriveMode);
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(test, derive(Debug))]
+    pub struct StartupConfiguration: u8 {
+        const TORQUE_ON = 0x01;
+        /// Restore RAM from the backup area (see `ControlTableBackup`).
+        const RAM_RESTORE = 0x02;
+    }
+}
+flags_value!(StartupConfiguration);
+
+bitflags::bitflags! {
+    /// Which hardware errors are present (`HardwareErrorStatus`)
+    /// or would turn torque off (`Shutdown`).
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(test, derive(Debug))]
+    pub struct HardwareErrors: u8 {
+        const INPUT_VOLTAGE = 0x01;
+        const OVERHEATING = 0x04;
+        const MOTOR_ENCODER = 0x08;
+        const ELECTRICAL_SHOCK = 0x10;
+        const OVERLOAD = 0x20;
+    }
+}
+flags_value!(HardwareErrors);
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(test, derive(Debug))]
+    pub struct MovingStatus: u8 {
+        const IN_POSITION = 0x01;
+        const PROFILE_ONGOING = 0x02;
+        const FOLLOWING_ERROR = 0x08;
+        /// Two bits: step, rectangular, triangular, or trapezoidal.
+        const VELOCITY_PROFILE = 0x30;
+    }
+}
+flags_value!(MovingStatus);
+
+#[cfg(test)]
+mod test {
+    use {super::*, quickcheck_macros::quickcheck};
+
+    #[quickcheck]
+    fn one_byte_values_roundtrip(byte: u8) -> bool {
+        OperatingMode::from_le_bytes([byte]).to_le_bytes() == [byte]
+            && DriveMode::from_le_bytes([byte]).to_le_bytes() == [byte]
+    }
+
+    #[quickcheck]
+    fn levels_answer_what_they_say(level: u8, instruction: u8) -> bool {
+        use crate::packet::Instruction;
+        let level = StatusReturnLevel::from_le_bytes([level]);
+        let answers = |instruction: Instruction| level.answers(instruction as u8);
+        match level {
+            StatusReturnLevel::PingOnly => {
+                answers(Instruction::Ping)
+                    && !answers(Instruction::Read)
+                    && !answers(Instruction::Write)
+            }
+            StatusReturnLevel::PingAndRead => {
+                answers(Instruction::Ping)
+                    && answers(Instruction::Read)
+                    && answers(Instruction::SyncRead)
+                    && answers(Instruction::BulkRead)
+                    && !answers(Instruction::Write)
+            }
+            StatusReturnLevel::All | StatusReturnLevel::Other(_) => level.answers(instruction),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! XL330 and XC330: every item in `control_table` but the external ports.
+
+use {
+    super::*,
+    crate::control_table::model::{Table, entries},
+};
+
+pub const TABLE: Table = Table {
+    name: "XL330 / XC330",
+    model_numbers: &[1190, 1200, 1210, 1220, 1230, 1240],
+    items: entries![
+        ModelNumber,
+        ModelInformation,
+        FirmwareVersion,
+        Id,
+        BaudRate,
+        ReturnDelayTime,
+        DriveMode,
+        OperatingMode,
+        SecondaryId,
+        ProtocolType,
+        HomingOffset,
+        MovingThreshold,
+        TemperatureLimit,
+        MaxVoltageLimit,
+        MinVoltageLimit,
+        PwmLimit,
+        CurrentLimit,
+        VelocityLimit,
+        MaxPositionLimit,
+        MinPositionLimit,
+        StartupConfiguration,
+        PwmSlope,
+        Shutdown,
+        TorqueEnable,
+        Led,
+        StatusReturnLevel,
+        RegisteredInstruction,
+        HardwareErrorStatus,
+        VelocityIGain,
+        VelocityPGain,
+        PositionDGain,
+        PositionIGain,
+        PositionPGain,
+        Feedforward2ndGain,
+        Feedforward1stGain,
+        BusWatchdog,
+        GoalPwm,
+        GoalCurrent,
+        GoalVelocity,
+        ProfileAcceleration,
+        ProfileVelocity,
+        GoalPosition,
+        RealtimeTick,
+        Moving,
+        MovingStatus,
+        PresentPwm,
+        PresentCurrent,
+        PresentVelocity,
+        PresentPosition,
+        VelocityTrajectory,
+        PositionTrajectory,
+        PresentInputVoltage,
+        PresentTemperature,
+        BackupReady,
+    ],
+    indirect: Some(indirect::X),
+};
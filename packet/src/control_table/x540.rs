@@ -0,0 +1,73 @@
+//! XM540, XH540, and XW540: the same as `xm430`, plus three external ports.
+
+use {
+    super::*,
+    crate::control_table::model::{Table, entries},
+};
+
+pub const TABLE: Table = Table {
+    name: "XM/XH/XW 540",
+    model_numbers: &[1100, 1110, 1120, 1130, 1140, 1150, 1170, 1180],
+    items: entries![
+        ModelNumber,
+        ModelInformation,
+        FirmwareVersion,
+        Id,
+        BaudRate,
+        ReturnDelayTime,
+        DriveMode,
+        OperatingMode,
+        SecondaryId,
+        ProtocolType,
+        HomingOffset,
+        MovingThreshold,
+        TemperatureLimit,
+        MaxVoltageLimit,
+        MinVoltageLimit,
+        PwmLimit,
+        CurrentLimit,
+        VelocityLimit,
+        MaxPositionLimit,
+        MinPositionLimit,
+        ExternalPortMode1,
+        ExternalPortMode2,
+        ExternalPortMode3,
+        StartupConfiguration,
+        Shutdown,
+        TorqueEnable,
+        Led,
+        StatusReturnLevel,
+        RegisteredInstruction,
+        HardwareErrorStatus,
+        VelocityIGain,
+        VelocityPGain,
+        PositionDGain,
+        PositionIGain,
+        PositionPGain,
+        Feedforward2ndGain,
+        Feedforward1stGain,
+        BusWatchdog,
+        GoalPwm,
+        GoalCurrent,
+        GoalVelocity,
+        ProfileAcceleration,
+        ProfileVelocity,
+        GoalPosition,
+        RealtimeTick,
+        Moving,
+        MovingStatus,
+        PresentPwm,
+        PresentCurrent,
+        PresentVelocity,
+        PresentPosition,
+        VelocityTrajectory,
+        PositionTrajectory,
+        PresentInputVoltage,
+        PresentTemperature,
+        BackupReady,
+        ExternalPortData1,
+        ExternalPortData2,
+        ExternalPortData3,
+    ],
+    indirect: Some(indirect::X),
+};
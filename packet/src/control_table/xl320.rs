@@ -0,0 +1,386 @@
+//! XL-320: Protocol 2.0, but an older, narrower layout that shares almost no addresses
+//! with the rest of the X-series, so it has its own items.
+
+use {
+    super::{Access, Area, Item, Writable, units::Quantity},
+    crate::control_table::model::{Table, entries},
+};
+
+pub struct ModelNumber;
+impl Item for ModelNumber {
+    const ADDRESS: u16 = 0;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Model Number";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Eeprom;
+    type Value = u16;
+}
+
+pub struct FirmwareVersion;
+impl Item for FirmwareVersion {
+    const ADDRESS: u16 = 2;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Firmware Version";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+
+pub struct Id;
+impl Item for Id {
+    const ADDRESS: u16 = 3;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "ID";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for Id {}
+
+pub struct BaudRate;
+impl Item for BaudRate {
+    const ADDRESS: u16 = 4;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Baud Rate";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for BaudRate {}
+
+pub struct ReturnDelayTime;
+impl Item for ReturnDelayTime {
+    const ADDRESS: u16 = 5;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Return Delay Time";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for ReturnDelayTime {}
+
+pub struct CwAngleLimit;
+impl Item for CwAngleLimit {
+    const ADDRESS: u16 = 6;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "CW Angle Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u16;
+}
+impl Writable for CwAngleLimit {}
+
+pub struct CcwAngleLimit;
+impl Item for CcwAngleLimit {
+    const ADDRESS: u16 = 8;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "CCW Angle Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u16;
+}
+impl Writable for CcwAngleLimit {}
+
+pub struct ControlMode;
+impl Item for ControlMode {
+    const ADDRESS: u16 = 11;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Control Mode";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for ControlMode {}
+
+pub struct TemperatureLimit;
+impl Item for TemperatureLimit {
+    const ADDRESS: u16 = 12;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Temperature Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+impl Writable for TemperatureLimit {}
+
+pub struct MinVoltageLimit;
+impl Item for MinVoltageLimit {
+    const ADDRESS: u16 = 13;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Min Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+impl Writable for MinVoltageLimit {}
+
+pub struct MaxVoltageLimit;
+impl Item for MaxVoltageLimit {
+    const ADDRESS: u16 = 14;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Max Voltage Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+impl Writable for MaxVoltageLimit {}
+
+pub struct MaxTorque;
+impl Item for MaxTorque {
+    const ADDRESS: u16 = 15;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Max Torque";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u16;
+}
+impl Writable for MaxTorque {}
+
+pub struct StatusReturnLevel;
+impl Item for StatusReturnLevel {
+    const ADDRESS: u16 = 17;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Status Return Level";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = super::value::StatusReturnLevel;
+}
+impl Writable for StatusReturnLevel {}
+
+pub struct Shutdown;
+impl Item for Shutdown {
+    const ADDRESS: u16 = 18;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Shutdown";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Eeprom;
+    type Value = u8;
+}
+impl Writable for Shutdown {}
+
+pub struct TorqueEnable;
+impl Item for TorqueEnable {
+    const ADDRESS: u16 = 24;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Torque Enable";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+impl Writable for TorqueEnable {}
+
+pub struct Led;
+impl Item for Led {
+    const ADDRESS: u16 = 25;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "LED";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for Led {}
+
+pub struct DGain;
+impl Item for DGain {
+    const ADDRESS: u16 = 27;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "D Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for DGain {}
+
+pub struct IGain;
+impl Item for IGain {
+    const ADDRESS: u16 = 28;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "I Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for IGain {}
+
+pub struct PGain;
+impl Item for PGain {
+    const ADDRESS: u16 = 29;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "P Gain";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+impl Writable for PGain {}
+
+pub struct GoalPosition;
+impl Item for GoalPosition {
+    const ADDRESS: u16 = 30;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Goal Position";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u16;
+}
+impl Writable for GoalPosition {}
+
+pub struct MovingSpeed;
+impl Item for MovingSpeed {
+    const ADDRESS: u16 = 32;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Moving Speed";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for MovingSpeed {}
+
+pub struct TorqueLimit;
+impl Item for TorqueLimit {
+    const ADDRESS: u16 = 35;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Torque Limit";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for TorqueLimit {}
+
+pub struct PresentPosition;
+impl Item for PresentPosition {
+    const ADDRESS: u16 = 37;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Position";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Position);
+    type Value = u16;
+}
+
+pub struct PresentSpeed;
+impl Item for PresentSpeed {
+    const ADDRESS: u16 = 39;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Speed";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct PresentLoad;
+impl Item for PresentLoad {
+    const ADDRESS: u16 = 41;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Load";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+
+pub struct PresentVoltage;
+impl Item for PresentVoltage {
+    const ADDRESS: u16 = 45;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Voltage";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Voltage);
+    type Value = u8;
+}
+
+pub struct PresentTemperature;
+impl Item for PresentTemperature {
+    const ADDRESS: u16 = 46;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Present Temperature";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    const QUANTITY: Option<Quantity> = Some(Quantity::Temperature);
+    type Value = u8;
+}
+
+pub struct RegisteredInstruction;
+impl Item for RegisteredInstruction {
+    const ADDRESS: u16 = 47;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Registered Instruction";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct Moving;
+impl Item for Moving {
+    const ADDRESS: u16 = 49;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Moving";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = bool;
+}
+
+pub struct HardwareErrorStatus;
+impl Item for HardwareErrorStatus {
+    const ADDRESS: u16 = 50;
+    const BYTES: u16 = 1;
+    const DESCRIPTION: &str = "Hardware Error Status";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = u8;
+}
+
+pub struct Punch;
+impl Item for Punch {
+    const ADDRESS: u16 = 51;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Punch";
+    const ACCESS: Access = Access::ReadWrite;
+    const AREA: Area = Area::Ram;
+    type Value = u16;
+}
+impl Writable for Punch {}
+
+pub const TABLE: Table = Table {
+    name: "XL-320",
+    model_numbers: &[350],
+    items: entries![
+        ModelNumber,
+        FirmwareVersion,
+        Id,
+        BaudRate,
+        ReturnDelayTime,
+        CwAngleLimit,
+        CcwAngleLimit,
+        ControlMode,
+        TemperatureLimit,
+        MinVoltageLimit,
+        MaxVoltageLimit,
+        MaxTorque,
+        StatusReturnLevel,
+        Shutdown,
+        TorqueEnable,
+        Led,
+        DGain,
+        IGain,
+        PGain,
+        GoalPosition,
+        MovingSpeed,
+        TorqueLimit,
+        PresentPosition,
+        PresentSpeed,
+        PresentLoad,
+        PresentVoltage,
+        PresentTemperature,
+        RegisteredInstruction,
+        Moving,
+        HardwareErrorStatus,
+        Punch,
+    ],
+    indirect: None,
+};
@@ -0,0 +1,76 @@
+//! XL430, 2XL430, XC430, and 2XC430: no current control, and load instead of current.
+
+use {
+    super::*,
+    crate::control_table::model::{Table, entries},
+};
+
+/// Where other models report current, these report load, in 0.1% of maximum torque.
+pub struct PresentLoad;
+impl Item for PresentLoad {
+    const ADDRESS: u16 = 126;
+    const BYTES: u16 = 2;
+    const DESCRIPTION: &str = "Present Load";
+    const ACCESS: Access = Access::ReadOnly;
+    const AREA: Area = Area::Ram;
+    type Value = i16;
+}
+
+pub const TABLE: Table = Table {
+    name: "XL430 / 2XL430 / XC430 / 2XC430",
+    model_numbers: &[1060, 1070, 1080, 1090, 1160],
+    items: entries![
+        ModelNumber,
+        ModelInformation,
+        FirmwareVersion,
+        Id,
+        BaudRate,
+        ReturnDelayTime,
+        DriveMode,
+        OperatingMode,
+        SecondaryId,
+        ProtocolType,
+        HomingOffset,
+        MovingThreshold,
+        TemperatureLimit,
+        MaxVoltageLimit,
+        MinVoltageLimit,
+        PwmLimit,
+        VelocityLimit,
+        MaxPositionLimit,
+        MinPositionLimit,
+        StartupConfiguration,
+        Shutdown,
+        TorqueEnable,
+        Led,
+        StatusReturnLevel,
+        RegisteredInstruction,
+        HardwareErrorStatus,
+        VelocityIGain,
+        VelocityPGain,
+        PositionDGain,
+        PositionIGain,
+        PositionPGain,
+        Feedforward2ndGain,
+        Feedforward1stGain,
+        BusWatchdog,
+        GoalPwm,
+        GoalVelocity,
+        ProfileAcceleration,
+        ProfileVelocity,
+        GoalPosition,
+        RealtimeTick,
+        Moving,
+        MovingStatus,
+        PresentPwm,
+        PresentLoad,
+        PresentVelocity,
+        PresentPosition,
+        VelocityTrajectory,
+        PositionTrajectory,
+        PresentInputVoltage,
+        PresentTemperature,
+        BackupReady,
+    ],
+    indirect: Some(indirect::X),
+};
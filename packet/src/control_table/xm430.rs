@@ -0,0 +1,67 @@
+//! XM430, XH430, and XW430: everything but `PwmSlope` and the external ports.
+
+use {
+    super::*,
+    crate::control_table::model::{Table, entries},
+};
+
+pub const TABLE: Table = Table {
+    name: "XM/XH/XW 430",
+    model_numbers: &[1000, 1010, 1020, 1030, 1040, 1050, 1270, 1280],
+    items: entries![
+        ModelNumber,
+        ModelInformation,
+        FirmwareVersion,
+        Id,
+        BaudRate,
+        ReturnDelayTime,
+        DriveMode,
+        OperatingMode,
+        SecondaryId,
+        ProtocolType,
+        HomingOffset,
+        MovingThreshold,
+        TemperatureLimit,
+        MaxVoltageLimit,
+        MinVoltageLimit,
+        PwmLimit,
+        CurrentLimit,
+        VelocityLimit,
+        MaxPositionLimit,
+        MinPositionLimit,
+        StartupConfiguration,
+        Shutdown,
+        TorqueEnable,
+        Led,
+        StatusReturnLevel,
+        RegisteredInstruction,
+        HardwareErrorStatus,
+        VelocityIGain,
+        VelocityPGain,
+        PositionDGain,
+        PositionIGain,
+        PositionPGain,
+        Feedforward2ndGain,
+        Feedforward1stGain,
+        BusWatchdog,
+        GoalPwm,
+        GoalCurrent,
+        GoalVelocity,
+        ProfileAcceleration,
+        ProfileVelocity,
+        GoalPosition,
+        RealtimeTick,
+        Moving,
+        MovingStatus,
+        PresentPwm,
+        PresentCurrent,
+        PresentVelocity,
+        PresentPosition,
+        VelocityTrajectory,
+        PositionTrajectory,
+        PresentInputVoltage,
+        PresentTemperature,
+        BackupReady,
+    ],
+    indirect: Some(indirect::X),
+};
@@ -8,9 +8,11 @@
 pub const MIN_ID: u8 = 0;
 pub const MAX_ID: u8 = 252;
 pub const N_IDS: u8 = MAX_ID - MIN_ID + 1;
+pub const BROADCAST_ID: u8 = 0xFE;
 
 pub mod control_table;
 pub mod crc;
+pub mod list;
 pub mod packet;
 pub mod parse;
 pub mod recv;
@@ -20,6 +22,8 @@ pub mod stream;
 pub trait Instruction: Sized + defmt::Format {
     const BYTE: u8;
     const GERUND: &'static str;
+    /// How (or whether) the parameters fit Protocol 1.0.
+    const V1: packet::v1::Translation = packet::v1::Translation::Unsupported;
 
     type Recv: recv::Receive;
 }
@@ -0,0 +1,54 @@
+//! Heterogeneous lists laid out back-to-back in memory,
+//! for packets whose entries each have a different type (e.g. Bulk Read).
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct Nil;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct Cons<Head: Copy, Tail: Copy> {
+    pub(crate) head: Head,
+    pub(crate) tail: Tail,
+}
+
+impl<Head: Copy, Tail: Copy> Cons<Head, Tail> {
+    #[inline(always)]
+    pub const fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+
+    #[inline(always)]
+    pub const fn head(&self) -> Head {
+        self.head
+    }
+
+    #[inline(always)]
+    pub const fn tail(&self) -> Tail {
+        self.tail
+    }
+}
+
+impl defmt::Format for Nil {
+    #[inline]
+    fn format(&self, _: defmt::Formatter) {}
+}
+
+impl<Head: Copy + defmt::Format, Tail: Copy + defmt::Format> defmt::Format for Cons<Head, Tail> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        let Self { head, tail } = *self;
+        defmt::write!(f, "{}, {}", head, tail)
+    }
+}
+
+/// Build a `Cons` list from a comma-separated list of expressions.
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::list::Nil
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::list::Cons::new($head, $crate::list!($($tail),*))
+    };
+}
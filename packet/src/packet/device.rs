@@ -0,0 +1,524 @@
+//! The other side of the bus: parsing instruction packets (and, for sniffers, status packets)
+//! as they arrive, and building status packets in reply, so firmware can act as a Dynamixel.
+
+use crate::{
+    New,
+    crc::Crc,
+    packet::{
+        dynamic::{self, WriteError},
+        recv::{Mismatch8, Mismatch16, ParseError, SoftwareError, Unstuff, WithId},
+    },
+    parse,
+};
+
+/// A decoded packet, borrowing its parameters from the parser's buffer.
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Request<'buffer> {
+    Ping,
+    Read {
+        address: u16,
+        length: u16,
+    },
+    Write {
+        address: u16,
+        data: &'buffer [u8],
+    },
+    RegWrite {
+        address: u16,
+        data: &'buffer [u8],
+    },
+    Action,
+    FactoryReset {
+        scope: u8,
+    },
+    Reboot,
+    SyncRead {
+        address: u16,
+        length: u16,
+        ids: &'buffer [u8],
+        /// Whether everyone answers in one combined status packet.
+        fast: bool,
+    },
+    SyncWrite(SyncWrite<'buffer>),
+    BulkRead {
+        entries: BulkRead<'buffer>,
+        /// Whether everyone answers in one combined status packet.
+        fast: bool,
+    },
+    BulkWrite(BulkWrite<'buffer>),
+    /// Another device's reply, for sniffers watching both directions.
+    Status {
+        error: u8,
+        parameters: &'buffer [u8],
+    },
+    /// Anything else (e.g. `Clear` or `ControlTableBackup`), undecoded.
+    Other {
+        instruction: u8,
+        parameters: &'buffer [u8],
+    },
+}
+
+/// `(id, data)` for each ID, every `data` the same length.
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct SyncWrite<'buffer> {
+    pub address: u16,
+    pub length: u16,
+    entries: &'buffer [u8],
+}
+
+impl<'buffer> SyncWrite<'buffer> {
+    #[inline]
+    pub fn entries(&self) -> impl Iterator<Item = (u8, &'buffer [u8])> {
+        self.entries
+            .chunks_exact(usize::from(self.length) + 1)
+            .map(|entry| (entry[0], &entry[1..]))
+    }
+}
+
+/// `(id, address, length)` for each ID.
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct BulkRead<'buffer> {
+    entries: &'buffer [u8],
+}
+
+impl<'buffer> BulkRead<'buffer> {
+    #[inline]
+    pub fn entries(&self) -> impl Iterator<Item = (u8, u16, u16)> + 'buffer {
+        self.entries.as_chunks::<5>().0.iter().map(
+            |&[id, address_lo, address_hi, length_lo, length_hi]| {
+                (
+                    id,
+                    u16::from_le_bytes([address_lo, address_hi]),
+                    u16::from_le_bytes([length_lo, length_hi]),
+                )
+            },
+        )
+    }
+}
+
+/// `(id, address, data)` for each ID, each `data` its own length.
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct BulkWrite<'buffer> {
+    entries: &'buffer [u8],
+}
+
+impl<'buffer> BulkWrite<'buffer> {
+    #[inline]
+    pub fn entries(&self) -> impl Iterator<Item = (u8, u16, &'buffer [u8])> {
+        let mut rest = self.entries;
+        core::iter::from_fn(move || {
+            let (&[id, address_lo, address_hi, length_lo, length_hi], after) =
+                rest.split_first_chunk::<5>()?;
+            let (data, after) =
+                after.split_at(usize::from(u16::from_le_bytes([length_lo, length_hi])));
+            rest = after;
+            Some((id, u16::from_le_bytes([address_lo, address_hi]), data))
+        })
+    }
+
+    /// Whether every entry's length fits inside the packet.
+    #[inline]
+    fn well_formed(entries: &[u8]) -> bool {
+        let mut rest = entries;
+        while !rest.is_empty() {
+            let Some((&[_, _, _, length_lo, length_hi], after)) = rest.split_first_chunk::<5>()
+            else {
+                return false;
+            };
+            let Some(after) = after.get(usize::from(u16::from_le_bytes([length_lo, length_hi]))..)
+            else {
+                return false;
+            };
+            rest = after;
+        }
+        true
+    }
+}
+
+#[inline]
+const fn u16_at(parameters: &[u8], i: usize) -> u16 {
+    u16::from_le_bytes([parameters[i], parameters[i + 1]])
+}
+
+impl<'buffer> Request<'buffer> {
+    /// Decode an instruction byte and its (unstuffed) parameters,
+    /// or `None` if there are too few or too many parameters for that instruction.
+    #[inline]
+    #[must_use]
+    pub fn decode(instruction: u8, parameters: &'buffer [u8]) -> Option<Self> {
+        let n = parameters.len();
+        Some(match instruction {
+            0x01 if n == 0 => Self::Ping,
+            0x02 if n == 4 => Self::Read {
+                address: u16_at(parameters, 0),
+                length: u16_at(parameters, 2),
+            },
+            0x03 if n >= 2 => Self::Write {
+                address: u16_at(parameters, 0),
+                data: &parameters[2..],
+            },
+            0x04 if n >= 2 => Self::RegWrite {
+                address: u16_at(parameters, 0),
+                data: &parameters[2..],
+            },
+            0x05 if n == 0 => Self::Action,
+            0x06 if n == 1 => Self::FactoryReset {
+                scope: parameters[0],
+            },
+            0x08 if n == 0 => Self::Reboot,
+            0x55 if n >= 1 => Self::Status {
+                error: parameters[0],
+                parameters: &parameters[1..],
+            },
+            0x82 | 0x8A if n >= 4 => Self::SyncRead {
+                address: u16_at(parameters, 0),
+                length: u16_at(parameters, 2),
+                ids: &parameters[4..],
+                fast: instruction == 0x8A,
+            },
+            0x83 if n >= 4 && (n - 4).is_multiple_of(usize::from(u16_at(parameters, 2)) + 1) => {
+                Self::SyncWrite(SyncWrite {
+                    address: u16_at(parameters, 0),
+                    length: u16_at(parameters, 2),
+                    entries: &parameters[4..],
+                })
+            }
+            0x92 | 0x9A if n.is_multiple_of(5) => Self::BulkRead {
+                entries: BulkRead {
+                    entries: parameters,
+                },
+                fast: instruction == 0x9A,
+            },
+            0x93 if BulkWrite::well_formed(parameters) => Self::BulkWrite(BulkWrite {
+                entries: parameters,
+            }),
+            0x01..=0x06 | 0x08 | 0x55 | 0x82 | 0x83 | 0x8A | 0x92 | 0x93 | 0x9A => return None,
+            _ => Self::Other {
+                instruction,
+                parameters,
+            },
+        })
+    }
+}
+
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Malformed {
+    BufferOverflow {
+        capacity: usize,
+    },
+    WrongParameterCount {
+        instruction: u8,
+        n_parameters: usize,
+    },
+}
+
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Error {
+    Parsing(ParseError<Malformed>),
+    Crc(Mismatch16),
+}
+
+enum Step {
+    Header1,
+    Header2,
+    Header3,
+    Reserved,
+    Id,
+    LengthLo {
+        id: u8,
+        crc_state: Crc,
+    },
+    LengthHi {
+        id: u8,
+        crc_state: Crc,
+        length_lo: u8,
+    },
+    Instruction {
+        id: u8,
+        crc_state: Crc,
+        length: u16,
+    },
+    Parameters {
+        id: u8,
+        crc_state: Crc,
+        instruction: u8,
+        remaining: u16,
+        unstuff: Unstuff,
+    },
+    FirstCrcByte {
+        id: u8,
+        instruction: u8,
+        expected_crc: u16,
+    },
+    SecondCrcByte {
+        id: u8,
+        instruction: u8,
+        expected_crc: u16,
+        first_crc_byte: u8,
+    },
+}
+
+/// Instruction-packet parser, unstuffing parameters into the buffer it was created with.
+/// Unlike a status-packet parser, this accepts the broadcast ID.
+pub struct Instruction<'buffer> {
+    buffer: &'buffer mut [u8],
+    filled: usize,
+    step: Step,
+}
+
+impl<'buffer> New for Instruction<'buffer> {
+    type Config = &'buffer mut [u8];
+
+    #[inline(always)]
+    fn new(buffer: Self::Config) -> Self {
+        Self {
+            buffer,
+            filled: 0,
+            step: Step::Header1,
+        }
+    }
+}
+
+impl<'buffer> parse::State<u8> for Instruction<'buffer> {
+    type Output = WithId<Request<'buffer>>;
+    type SideEffect = ();
+    type Error = Error;
+
+    #[inline]
+    #[expect(clippy::too_many_lines, reason = "Lots of cases in a single match.")]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        macro_rules! expect {
+            ($byte:expr, $err:ident, $next:ident) => {
+                if input == $byte {
+                    Step::$next
+                } else {
+                    return Err(Error::Parsing(ParseError::$err(Mismatch8 {
+                        expected: $byte,
+                        actual: input,
+                    })));
+                }
+            };
+        }
+
+        let Self {
+            buffer,
+            mut filled,
+            step,
+        } = self;
+        let step = match step {
+            Step::Header1 => expect!(0xFF, WrongFirstHeaderByte, Header2),
+            Step::Header2 => expect!(0xFF, WrongSecondHeaderByte, Header3),
+            Step::Header3 => expect!(0xFD, WrongThirdHeaderByte, Reserved),
+            Step::Reserved => expect!(0x00, WrongReservedByte, Id),
+            Step::Id => {
+                if !(crate::MIN_ID..=crate::MAX_ID).contains(&input) && input != crate::BROADCAST_ID
+                {
+                    return Err(Error::Parsing(ParseError::InvalidId { id: input }));
+                }
+                let mut crc_state = Crc::new();
+                let () = crc_state.recurse_over_bytes(&[0xFF, 0xFF, 0xFD, 0x00, input]);
+                Step::LengthLo {
+                    id: input,
+                    crc_state,
+                }
+            }
+            Step::LengthLo { id, mut crc_state } => {
+                let () = crc_state.push(input);
+                Step::LengthHi {
+                    id,
+                    crc_state,
+                    length_lo: input,
+                }
+            }
+            Step::LengthHi {
+                id,
+                mut crc_state,
+                length_lo,
+            } => {
+                let () = crc_state.push(input);
+                let length = u16::from_le_bytes([length_lo, input]);
+                // Instruction and CRC:
+                if length < 3 {
+                    return Err(Error::Parsing(ParseError::WrongLength(Mismatch16 {
+                        expected: 3,
+                        actual: length,
+                    })));
+                }
+                Step::Instruction {
+                    id,
+                    crc_state,
+                    length,
+                }
+            }
+            Step::Instruction {
+                id,
+                mut crc_state,
+                length,
+            } => {
+                let () = crc_state.push(input);
+                match length - 3 {
+                    0 => Step::FirstCrcByte {
+                        id,
+                        instruction: input,
+                        expected_crc: crc_state.collapse(),
+                    },
+                    remaining => Step::Parameters {
+                        id,
+                        crc_state,
+                        instruction: input,
+                        remaining,
+                        unstuff: Unstuff::new(),
+                    },
+                }
+            }
+            Step::Parameters {
+                id,
+                mut crc_state,
+                instruction,
+                remaining,
+                mut unstuff,
+            } => {
+                let () = crc_state.push(input);
+                if unstuff.push(input) {
+                    let Some(slot) = buffer.get_mut(filled) else {
+                        return Err(Error::Parsing(ParseError::InstructionSpecific(
+                            Malformed::BufferOverflow {
+                                capacity: buffer.len(),
+                            },
+                        )));
+                    };
+                    *slot = input;
+                    filled += 1;
+                }
+                match remaining - 1 {
+                    0 => Step::FirstCrcByte {
+                        id,
+                        instruction,
+                        expected_crc: crc_state.collapse(),
+                    },
+                    remaining => Step::Parameters {
+                        id,
+                        crc_state,
+                        instruction,
+                        remaining,
+                        unstuff,
+                    },
+                }
+            }
+            Step::FirstCrcByte {
+                id,
+                instruction,
+                expected_crc,
+            } => Step::SecondCrcByte {
+                id,
+                instruction,
+                expected_crc,
+                first_crc_byte: input,
+            },
+            Step::SecondCrcByte {
+                id,
+                instruction,
+                expected_crc,
+                first_crc_byte,
+            } => {
+                let actual_crc = u16::from_le_bytes([first_crc_byte, input]);
+                if actual_crc != expected_crc {
+                    return Err(Error::Crc(Mismatch16 {
+                        expected: expected_crc,
+                        actual: actual_crc,
+                    }));
+                }
+                let buffer: &'buffer [u8] = buffer;
+                let parameters = &buffer[..filled];
+                let Some(output) = Request::decode(instruction, parameters) else {
+                    return Err(Error::Parsing(ParseError::InstructionSpecific(
+                        Malformed::WrongParameterCount {
+                            instruction,
+                            n_parameters: filled,
+                        },
+                    )));
+                };
+                return Ok(parse::Status::Complete(WithId { id, output }));
+            }
+        };
+        Ok(parse::Status::Incomplete((
+            Self {
+                buffer,
+                filled,
+                step,
+            },
+            (),
+        )))
+    }
+}
+
+/// Write a status packet replying to an instruction into `buffer`, returning its size in bytes.
+/// `buffer` must have room for `dynamic::max_size(parameters.len() + 1)` bytes.
+#[inline]
+pub fn write_status(
+    buffer: &mut [u8],
+    id: u8,
+    software_error: Option<SoftwareError>,
+    hardware_error: bool,
+    parameters: &[u8],
+) -> Result<usize, WriteError> {
+    let error = software_error.map_or(0, |e| e as u8) | if hardware_error { 0x80 } else { 0 };
+    dynamic::write_parts(buffer, id, 0x55, &[&[error], parameters])
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{control_table, packet, send},
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn write_roundtrip(id: u8, value: u32) -> TestResult {
+        let id = id % (crate::MAX_ID + 1);
+        let packet = packet::new(
+            id,
+            send::Write::<control_table::GoalPosition, 4>::new(value.to_le_bytes()),
+        );
+        let packet = packet.as_buffer();
+
+        let mut buffer = [0; 6];
+        let mut state = Instruction::new(&mut buffer);
+        for (i, &byte) in packet.iter().enumerate() {
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(parse::Status::Complete(WithId {
+                    id: actual_id,
+                    output,
+                })) => {
+                    let expected = Request::Write {
+                        address: 116,
+                        data: &value.to_le_bytes(),
+                    };
+                    return if i + 1 != packet.len() {
+                        TestResult::error(format!("{packet:02X?} finished early at byte #{i}"))
+                    } else if (actual_id, &output) != (id, &expected) {
+                        TestResult::error(format!(
+                            "{packet:02X?} -> ID {actual_id}, {output:?} =/= ID {id}, {expected:?}"
+                        ))
+                    } else {
+                        TestResult::passed()
+                    };
+                }
+                Err(e) => return TestResult::error(format!("{packet:02X?} -> {e:?}")),
+            };
+        }
+        TestResult::error(format!("{packet:02X?} never finished"))
+    }
+}
@@ -0,0 +1,403 @@
+//! Packets whose instruction and parameters are only known at runtime
+//! (e.g. an address and length from a CLI or a config file),
+//! built into and parsed from caller-provided buffers instead of const-generic types.
+
+use crate::{
+    New,
+    crc::Crc,
+    packet::{
+        recv::{Error, Mismatch8, Mismatch16, ParseError, SoftwareError, Unstuff, WithId},
+        send,
+    },
+    parse,
+};
+
+/// Buffer size that always fits an instruction packet with this many parameters,
+/// even if every parameter needs stuffing.
+#[inline]
+#[must_use]
+pub const fn max_size(n_parameters: usize) -> usize {
+    send::PREFIX_BYTES + n_parameters + n_parameters / 3 + 2
+}
+
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum WriteError {
+    BufferTooSmall { needed: usize, capacity: usize },
+    TooManyParameters { n_parameters: usize },
+}
+
+/// Write an instruction packet into `buffer`, returning its size in bytes.
+/// `buffer` must have room for `max_size(parameters.len())` bytes.
+#[inline]
+pub fn write(
+    buffer: &mut [u8],
+    id: u8,
+    instruction: u8,
+    parameters: &[u8],
+) -> Result<usize, WriteError> {
+    write_parts(buffer, id, instruction, &[parameters])
+}
+
+/// Like `write`, but with parameters split across several slices
+/// (e.g. a status packet's error byte, then its data).
+#[inline]
+pub(crate) fn write_parts(
+    buffer: &mut [u8],
+    id: u8,
+    instruction: u8,
+    parts: &[&[u8]],
+) -> Result<usize, WriteError> {
+    let n_parameters: usize = parts.iter().map(|part| part.len()).sum();
+    let needed = max_size(n_parameters);
+    if buffer.len() < needed {
+        return Err(WriteError::BufferTooSmall {
+            needed,
+            capacity: buffer.len(),
+        });
+    }
+    if needed - send::PREFIX_BYTES + 1 > usize::from(u16::MAX) {
+        return Err(WriteError::TooManyParameters { n_parameters });
+    }
+    let (prefix, rest) = buffer.split_at_mut(send::PREFIX_BYTES);
+    let n_parameters = {
+        let mut filled = 0;
+        for part in parts {
+            rest[filled..filled + part.len()].copy_from_slice(part);
+            filled += part.len();
+        }
+        send::stuff(rest.as_mut_ptr(), filled)
+    };
+    let [length_lo, length_hi] = (n_parameters as u16 + 3).to_le_bytes();
+    prefix.copy_from_slice(&[
+        0xFF,
+        0xFF,
+        0xFD,
+        0x00,
+        id,
+        length_lo,
+        length_hi,
+        instruction,
+    ]);
+    let size = send::PREFIX_BYTES + n_parameters;
+    let crc = {
+        let mut crc_state = Crc::new();
+        let () = crc_state.recurse_over_bytes(&buffer[..size]);
+        crc_state.collapse().to_le_bytes()
+    };
+    buffer[size..size + 2].copy_from_slice(&crc);
+    Ok(size + 2)
+}
+
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct BufferOverflow {
+    pub capacity: usize,
+}
+
+enum Step {
+    Header1,
+    Header2,
+    Header3,
+    Reserved,
+    Id,
+    LengthLo {
+        id: u8,
+        crc_state: Crc,
+    },
+    LengthHi {
+        id: u8,
+        crc_state: Crc,
+        length_lo: u8,
+    },
+    Instruction {
+        id: u8,
+        crc_state: Crc,
+        length: u16,
+    },
+    ErrorByte {
+        id: u8,
+        crc_state: Crc,
+        length: u16,
+    },
+    Parameters {
+        id: u8,
+        crc_state: Crc,
+        error: u8,
+        remaining: u16,
+        unstuff: Unstuff,
+    },
+    FirstCrcByte {
+        id: u8,
+        error: u8,
+        expected_crc: u16,
+    },
+    SecondCrcByte {
+        id: u8,
+        error: u8,
+        expected_crc: u16,
+        first_crc_byte: u8,
+    },
+}
+
+/// Status-packet parser that accepts any number of parameters,
+/// unstuffing them into the buffer it was created with.
+pub struct Status<'buffer> {
+    buffer: &'buffer mut [u8],
+    filled: usize,
+    step: Step,
+}
+
+impl<'buffer> New for Status<'buffer> {
+    type Config = &'buffer mut [u8];
+
+    #[inline(always)]
+    fn new(buffer: Self::Config) -> Self {
+        Self {
+            buffer,
+            filled: 0,
+            step: Step::Header1,
+        }
+    }
+}
+
+impl<'buffer> parse::State<u8> for Status<'buffer> {
+    type Output = WithId<&'buffer [u8]>;
+    type SideEffect = ();
+    type Error = Error<Self::Output, BufferOverflow>;
+
+    #[inline]
+    #[expect(clippy::too_many_lines, reason = "Lots of cases in a single match.")]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        macro_rules! expect {
+            ($byte:expr, $err:ident, $next:ident) => {
+                if input == $byte {
+                    Step::$next
+                } else {
+                    return Err(Error::Parsing(ParseError::$err(Mismatch8 {
+                        expected: $byte,
+                        actual: input,
+                    })));
+                }
+            };
+        }
+
+        let Self {
+            buffer,
+            mut filled,
+            step,
+        } = self;
+        let step = match step {
+            Step::Header1 => expect!(0xFF, WrongFirstHeaderByte, Header2),
+            Step::Header2 => expect!(0xFF, WrongSecondHeaderByte, Header3),
+            Step::Header3 => expect!(0xFD, WrongThirdHeaderByte, Reserved),
+            Step::Reserved => expect!(0x00, WrongReservedByte, Id),
+            Step::Id => {
+                if !(crate::MIN_ID..=crate::MAX_ID).contains(&input) {
+                    return Err(Error::Parsing(ParseError::InvalidId { id: input }));
+                }
+                let mut crc_state = Crc::new();
+                let () = crc_state.recurse_over_bytes(&[0xFF, 0xFF, 0xFD, 0x00, input]);
+                Step::LengthLo {
+                    id: input,
+                    crc_state,
+                }
+            }
+            Step::LengthLo { id, mut crc_state } => {
+                let () = crc_state.push(input);
+                Step::LengthHi {
+                    id,
+                    crc_state,
+                    length_lo: input,
+                }
+            }
+            Step::LengthHi {
+                id,
+                mut crc_state,
+                length_lo,
+            } => {
+                let () = crc_state.push(input);
+                let length = u16::from_le_bytes([length_lo, input]);
+                // Instruction, error, and CRC:
+                if length < 4 {
+                    return Err(Error::Parsing(ParseError::WrongLength(Mismatch16 {
+                        expected: 4,
+                        actual: length,
+                    })));
+                }
+                Step::Instruction {
+                    id,
+                    crc_state,
+                    length,
+                }
+            }
+            Step::Instruction {
+                id,
+                mut crc_state,
+                length,
+            } => {
+                if input != 0x55 {
+                    return Err(Error::Parsing(ParseError::WrongInstruction(Mismatch8 {
+                        expected: 0x55,
+                        actual: input,
+                    })));
+                }
+                let () = crc_state.push(input);
+                Step::ErrorByte {
+                    id,
+                    crc_state,
+                    length,
+                }
+            }
+            Step::ErrorByte {
+                id,
+                mut crc_state,
+                length,
+            } => {
+                let () = crc_state.push(input);
+                let _: Option<SoftwareError> = SoftwareError::check(input)
+                    .map_err(|e| Error::Parsing(ParseError::InvalidSoftwareError(e)))?;
+                match length - 4 {
+                    0 => Step::FirstCrcByte {
+                        id,
+                        error: input,
+                        expected_crc: crc_state.collapse(),
+                    },
+                    remaining => Step::Parameters {
+                        id,
+                        crc_state,
+                        error: input,
+                        remaining,
+                        unstuff: Unstuff::new(),
+                    },
+                }
+            }
+            Step::Parameters {
+                id,
+                mut crc_state,
+                error,
+                remaining,
+                mut unstuff,
+            } => {
+                let () = crc_state.push(input);
+                if unstuff.push(input) {
+                    let Some(slot) = buffer.get_mut(filled) else {
+                        return Err(Error::Parsing(ParseError::InstructionSpecific(
+                            BufferOverflow {
+                                capacity: buffer.len(),
+                            },
+                        )));
+                    };
+                    *slot = input;
+                    filled += 1;
+                }
+                match remaining - 1 {
+                    0 => Step::FirstCrcByte {
+                        id,
+                        error,
+                        expected_crc: crc_state.collapse(),
+                    },
+                    remaining => Step::Parameters {
+                        id,
+                        crc_state,
+                        error,
+                        remaining,
+                        unstuff,
+                    },
+                }
+            }
+            Step::FirstCrcByte {
+                id,
+                error,
+                expected_crc,
+            } => Step::SecondCrcByte {
+                id,
+                error,
+                expected_crc,
+                first_crc_byte: input,
+            },
+            Step::SecondCrcByte {
+                id,
+                error,
+                expected_crc,
+                first_crc_byte,
+            } => {
+                let actual_crc = u16::from_le_bytes([first_crc_byte, input]);
+                if actual_crc != expected_crc {
+                    return Err(Error::Crc(Mismatch16 {
+                        expected: expected_crc,
+                        actual: actual_crc,
+                    }));
+                }
+                // Already checked above:
+                if let Ok(Some(output)) = SoftwareError::check(error) {
+                    return Err(Error::Software(WithId { id, output }));
+                }
+                let buffer: &'buffer [u8] = buffer;
+                let with_id = WithId {
+                    id,
+                    output: &buffer[..filled],
+                };
+                return if (error & 0x80) != 0 {
+                    Err(Error::Hardware(with_id))
+                } else {
+                    Ok(parse::Status::Complete(with_id))
+                };
+            }
+        };
+        Ok(parse::Status::Incomplete((
+            Self {
+                buffer,
+                filled,
+                step,
+            },
+            (),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, quickcheck::TestResult, quickcheck_macros::quickcheck};
+
+    #[quickcheck]
+    fn status_roundtrip(id: u8, parameters: Vec<u8>) -> TestResult {
+        let id = id % (crate::MAX_ID + 1);
+        // A status packet is just an instruction packet whose first parameter is the error:
+        let mut with_error = vec![0x00];
+        with_error.extend_from_slice(&parameters);
+        let mut packet = vec![0; max_size(with_error.len())];
+        let size = match write(&mut packet, id, 0x55, &with_error) {
+            Ok(ok) => ok,
+            Err(e) => return TestResult::error(format!("{e:?}")),
+        };
+        packet.truncate(size);
+
+        let mut buffer = vec![0; parameters.len()];
+        let mut state = Status::new(&mut buffer);
+        for (i, &byte) in packet.iter().enumerate() {
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(parse::Status::Complete(WithId {
+                    id: actual_id,
+                    output,
+                })) => {
+                    return if i + 1 != packet.len() {
+                        TestResult::error(format!("{packet:02X?} finished early at byte #{i}"))
+                    } else if (actual_id, output) != (id, &parameters[..]) {
+                        TestResult::error(format!(
+                            "{packet:02X?} -> ID {actual_id}, {output:02X?} =/= ID {id}, {parameters:02X?}"
+                        ))
+                    } else {
+                        TestResult::passed()
+                    };
+                }
+                Err(e) => return TestResult::error(format!("{packet:02X?} -> {e:?}")),
+            };
+        }
+        TestResult::error(format!("{packet:02X?} never finished"))
+    }
+}
@@ -0,0 +1,709 @@
+//! Fast Sync Read and Fast Bulk Read, whose responses are combined into one status packet
+//! "from" the broadcast ID: each ID contributes its error byte, its ID, its data, and a CRC,
+//! except that the last ID's CRC is the CRC of the whole packet
+//! (which also covers every intermediate CRC, so those are skipped rather than checked).
+
+use {
+    crate::{
+        New, control_table,
+        crc::Crc,
+        list::{Cons, Nil},
+        packet::recv::{
+            Mismatch8, Mismatch16, ParseError, PersistentError, SoftwareError, Unstuff, WithId,
+        },
+        parse, recv,
+        send::{self, BulkReadEntries, BulkReadEntry},
+    },
+    core::{convert::Infallible, mem::MaybeUninit},
+};
+
+/// One ID's share of a combined status packet.
+#[derive(defmt::Format)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct Portion<Output> {
+    pub id: u8,
+    pub output: Result<Output, SoftwareError>,
+    pub hardware_error: bool,
+}
+
+impl<Output> Portion<Output> {
+    #[inline]
+    pub fn into_result(self) -> WithId<Result<Output, PersistentError<Output>>> {
+        let Self {
+            id,
+            output,
+            hardware_error,
+        } = self;
+        WithId {
+            id,
+            output: match output {
+                Err(e) => Err(PersistentError::Software(e)),
+                Ok(output) if hardware_error => Err(PersistentError::Hardware(output)),
+                Ok(output) => Ok(output),
+            },
+        }
+    }
+}
+
+/// Everything between the instruction byte and the final CRC of a combined status packet.
+pub trait Portions {
+    /// Bytes from the first error byte through the last ID's data, before stuffing.
+    const BYTES: usize;
+    type Output;
+    type Parser: parse::State<
+            u8,
+            Output = Self::Output,
+            SideEffect = (),
+            Error = ParseError<Infallible>,
+            Config = (),
+        >;
+    /// The first ID this asks to answer for which `predicate` holds
+    /// (e.g. one that won't answer, which would spoil the whole response).
+    fn find_id(&self, predicate: impl FnMut(u8) -> bool) -> Option<u8>;
+}
+
+pub enum Single<const BYTES: usize> {
+    ErrorByte,
+    Id {
+        software_error: Option<SoftwareError>,
+        hardware_error: bool,
+    },
+    Data {
+        id: u8,
+        software_error: Option<SoftwareError>,
+        hardware_error: bool,
+        state: parse::ByteArray<BYTES>,
+    },
+}
+
+impl<const BYTES: usize> New for Single<BYTES> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self::ErrorByte
+    }
+}
+
+impl<const BYTES: usize> parse::State<u8> for Single<BYTES> {
+    type Output = Portion<recv::Read<BYTES>>;
+    type SideEffect = ();
+    type Error = ParseError<Infallible>;
+
+    #[inline]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        Ok(parse::Status::Incomplete((
+            match self {
+                Self::ErrorByte => Self::Id {
+                    software_error: SoftwareError::check(input)
+                        .map_err(ParseError::InvalidSoftwareError)?,
+                    hardware_error: (input & 0x80) != 0,
+                },
+                Self::Id {
+                    software_error,
+                    hardware_error,
+                } => {
+                    if BYTES == 0 {
+                        return Ok(parse::Status::Complete(Portion {
+                            id: input,
+                            output: software_error
+                                .map_or(Ok(recv::Read { bytes: [0; BYTES] }), Err),
+                            hardware_error,
+                        }));
+                    }
+                    Self::Data {
+                        id: input,
+                        software_error,
+                        hardware_error,
+                        state: parse::ByteArray::new(()),
+                    }
+                }
+                Self::Data {
+                    id,
+                    software_error,
+                    hardware_error,
+                    state,
+                } => match parse::State::push(state, input) {
+                    Ok(parse::Status::Complete(bytes)) => {
+                        return Ok(parse::Status::Complete(Portion {
+                            id,
+                            // Data still arrives after a software error, but it means nothing:
+                            output: software_error.map_or(Ok(recv::Read { bytes }), Err),
+                            hardware_error,
+                        }));
+                    }
+                    Ok(parse::Status::Incomplete((state, ()))) => Self::Data {
+                        id,
+                        software_error,
+                        hardware_error,
+                        state,
+                    },
+                    Err(e) => match e {},
+                },
+            },
+            (),
+        )))
+    }
+}
+
+pub enum SyncStep<const BYTES: usize> {
+    Portion(Single<BYTES>),
+    FirstCrcByte,
+    SecondCrcByte,
+}
+
+pub struct Sync<const BYTES: usize, const N: usize> {
+    index: usize,
+    step: SyncStep<BYTES>,
+    portions: [MaybeUninit<Portion<recv::Read<BYTES>>>; N],
+}
+
+impl<const BYTES: usize, const N: usize> New for Sync<BYTES, N> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self {
+            index: 0,
+            step: SyncStep::Portion(Single::new(())),
+            portions: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<const BYTES: usize, const N: usize> parse::State<u8> for Sync<BYTES, N> {
+    type Output = [Portion<recv::Read<BYTES>>; N];
+    type SideEffect = ();
+    type Error = ParseError<Infallible>;
+
+    #[inline]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        let Self {
+            mut index,
+            step,
+            mut portions,
+        } = self;
+        let step = match step {
+            SyncStep::Portion(state) => match parse::State::push(state, input)? {
+                parse::Status::Incomplete((state, ())) => SyncStep::Portion(state),
+                parse::Status::Complete(portion) => {
+                    if let Some(uninit) = portions.get_mut(index) {
+                        uninit.write(portion);
+                    }
+                    index += 1;
+                    if index >= N {
+                        return Ok(parse::Status::Complete({
+                            let ptr: *const _ = &portions;
+                            let cast: *const Self::Output = ptr.cast();
+                            unsafe { cast.read() }
+                        }));
+                    }
+                    SyncStep::FirstCrcByte
+                }
+            },
+            SyncStep::FirstCrcByte => SyncStep::SecondCrcByte,
+            SyncStep::SecondCrcByte => SyncStep::Portion(Single::new(())),
+        };
+        Ok(parse::Status::Incomplete((
+            Self {
+                index,
+                step,
+                portions,
+            },
+            (),
+        )))
+    }
+}
+
+impl<Address: control_table::Item, const N: usize> Portions for send::FastSyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const BYTES: usize = (N * (Address::BYTES as usize + 4)).saturating_sub(2);
+    type Output = [Portion<recv::Read<{ Address::BYTES as usize }>>; N];
+    type Parser = Sync<{ Address::BYTES as usize }, N>;
+
+    #[inline]
+    fn find_id(&self, mut predicate: impl FnMut(u8) -> bool) -> Option<u8> {
+        let ids = self.ids;
+        ids.into_iter().find(|&id| predicate(id))
+    }
+}
+
+/// The last entry of a Fast Bulk Read, which has no CRC of its own.
+pub struct Last<const BYTES: usize>(Single<BYTES>);
+
+impl<const BYTES: usize> New for Last<BYTES> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self(Single::new(()))
+    }
+}
+
+impl<const BYTES: usize> parse::State<u8> for Last<BYTES> {
+    type Output = (Portion<recv::Read<BYTES>>, ());
+    type SideEffect = ();
+    type Error = ParseError<Infallible>;
+
+    #[inline(always)]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        let Self(state) = self;
+        Ok(match parse::State::push(state, input)? {
+            parse::Status::Complete(portion) => parse::Status::Complete((portion, ())),
+            parse::Status::Incomplete((state, ())) => parse::Status::Incomplete((Self(state), ())),
+        })
+    }
+}
+
+pub enum Bulk<const BYTES: usize, Tail: Portions> {
+    Head(Single<BYTES>),
+    FirstCrcByte {
+        head: Portion<recv::Read<BYTES>>,
+    },
+    SecondCrcByte {
+        head: Portion<recv::Read<BYTES>>,
+    },
+    Tail {
+        head: Portion<recv::Read<BYTES>>,
+        state: Tail::Parser,
+    },
+}
+
+impl<const BYTES: usize, Tail: Portions> New for Bulk<BYTES, Tail> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self::Head(Single::new(()))
+    }
+}
+
+impl<const BYTES: usize, Tail: Portions> parse::State<u8> for Bulk<BYTES, Tail> {
+    type Output = (Portion<recv::Read<BYTES>>, Tail::Output);
+    type SideEffect = ();
+    type Error = ParseError<Infallible>;
+
+    #[inline]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        Ok(parse::Status::Incomplete((
+            match self {
+                Self::Head(state) => match parse::State::push(state, input)? {
+                    parse::Status::Complete(head) => Self::FirstCrcByte { head },
+                    parse::Status::Incomplete((state, ())) => Self::Head(state),
+                },
+                Self::FirstCrcByte { head } => Self::SecondCrcByte { head },
+                Self::SecondCrcByte { head } => Self::Tail {
+                    head,
+                    state: Tail::Parser::new(()),
+                },
+                Self::Tail { head, state } => match parse::State::push(state, input)? {
+                    parse::Status::Complete(tail) => {
+                        return Ok(parse::Status::Complete((head, tail)));
+                    }
+                    parse::Status::Incomplete((state, ())) => Self::Tail { head, state },
+                },
+            },
+            (),
+        )))
+    }
+}
+
+impl<Address: control_table::Item> Portions for Cons<BulkReadEntry<Address>, Nil>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const BYTES: usize = Address::BYTES as usize + 2;
+    type Output = (Portion<recv::Read<{ Address::BYTES as usize }>>, ());
+    type Parser = Last<{ Address::BYTES as usize }>;
+
+    #[inline]
+    fn find_id(&self, mut predicate: impl FnMut(u8) -> bool) -> Option<u8> {
+        let id = self.head().id();
+        predicate(id).then_some(id)
+    }
+}
+
+impl<Address: control_table::Item, Head: Copy, Tail: Copy> Portions
+    for Cons<BulkReadEntry<Address>, Cons<Head, Tail>>
+where
+    [(); Address::BYTES as usize]:,
+    Cons<Head, Tail>: Portions,
+{
+    const BYTES: usize = Address::BYTES as usize + 4 + <Cons<Head, Tail> as Portions>::BYTES;
+    type Output = (
+        Portion<recv::Read<{ Address::BYTES as usize }>>,
+        <Cons<Head, Tail> as Portions>::Output,
+    );
+    type Parser = Bulk<{ Address::BYTES as usize }, Cons<Head, Tail>>;
+
+    #[inline]
+    fn find_id(&self, mut predicate: impl FnMut(u8) -> bool) -> Option<u8> {
+        let id = self.head().id();
+        if predicate(id) {
+            Some(id)
+        } else {
+            self.tail().find_id(predicate)
+        }
+    }
+}
+
+impl<Entries: BulkReadEntries + Portions> Portions for send::FastBulkRead<Entries> {
+    const BYTES: usize = Entries::BYTES;
+    type Output = Entries::Output;
+    type Parser = Entries::Parser;
+
+    #[inline(always)]
+    fn find_id(&self, predicate: impl FnMut(u8) -> bool) -> Option<u8> {
+        let entries = self.entries;
+        entries.find_id(predicate)
+    }
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Error {
+    Parsing(ParseError<Infallible>),
+    NotBroadcast(Mismatch8),
+    Crc(Mismatch16),
+}
+
+impl defmt::Format for Error {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::Parsing(ref e) => defmt::write!(f, "Parsing error: {}", e),
+            Self::NotBroadcast(ref e) => {
+                defmt::write!(f, "Combined response not from the broadcast ID: {}", e)
+            }
+            Self::Crc(ref e) => defmt::write!(f, "CRC error: {}", e),
+        }
+    }
+}
+
+pub enum Fast<P: Portions> {
+    Header1,
+    Header2,
+    Header3,
+    Reserved,
+    Id,
+    LengthLo {
+        crc_state: Crc,
+    },
+    LengthHi {
+        crc_state: Crc,
+        length_lo: u8,
+    },
+    Instruction {
+        crc_state: Crc,
+        length: u16,
+    },
+    Portions {
+        state: P::Parser,
+        crc_state: Crc,
+        length: u16,
+        remaining: u16,
+        unstuff: Unstuff,
+    },
+    TrailingStuffing {
+        output: P::Output,
+        crc_state: Crc,
+    },
+    FirstCrcByte {
+        output: P::Output,
+        expected_crc: u16,
+    },
+    SecondCrcByte {
+        output: P::Output,
+        expected_crc: u16,
+        first_crc_byte: u8,
+    },
+}
+
+impl<P: Portions> Fast<P> {
+    #[inline]
+    const fn crc_init() -> Crc {
+        let mut crc = Crc::new();
+        let () = crc.push(0xFF);
+        let () = crc.push(0xFF);
+        let () = crc.push(0xFD);
+        let () = crc.push(0x00);
+        crc
+    }
+}
+
+impl<P: Portions> New for Fast<P> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self::Header1
+    }
+}
+
+impl<P: Portions> parse::State<u8> for Fast<P> {
+    type Output = P::Output;
+    type SideEffect = ();
+    type Error = Error;
+
+    #[inline]
+    #[expect(clippy::too_many_lines, reason = "Lots of cases in a single match.")]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        macro_rules! expect {
+            ($byte:expr, $err:ident, $next:ident) => {
+                if input == $byte {
+                    Self::$next
+                } else {
+                    return Err(Error::Parsing(ParseError::$err(Mismatch8 {
+                        expected: $byte,
+                        actual: input,
+                    })));
+                }
+            };
+        }
+
+        let wrong_length = |length| {
+            Error::Parsing(ParseError::WrongLength(Mismatch16 {
+                actual: length,
+                expected: const { (P::BYTES + 3) as u16 },
+            }))
+        };
+
+        Ok(parse::Status::Incomplete((
+            match self {
+                Self::Header1 => expect!(0xFF, WrongFirstHeaderByte, Header2),
+                Self::Header2 => expect!(0xFF, WrongSecondHeaderByte, Header3),
+                Self::Header3 => expect!(0xFD, WrongThirdHeaderByte, Reserved),
+                Self::Reserved => expect!(0x00, WrongReservedByte, Id),
+                Self::Id => {
+                    if input != crate::BROADCAST_ID {
+                        return Err(Error::NotBroadcast(Mismatch8 {
+                            expected: crate::BROADCAST_ID,
+                            actual: input,
+                        }));
+                    }
+                    let mut crc_state = const { Fast::<P>::crc_init() };
+                    let () = crc_state.push(input);
+                    Self::LengthLo { crc_state }
+                }
+                Self::LengthLo { mut crc_state } => {
+                    let () = crc_state.push(input);
+                    Self::LengthHi {
+                        crc_state,
+                        length_lo: input,
+                    }
+                }
+                Self::LengthHi {
+                    mut crc_state,
+                    length_lo,
+                } => {
+                    let () = crc_state.push(input);
+                    Self::Instruction {
+                        crc_state,
+                        length: u16::from_le_bytes([length_lo, input]),
+                    }
+                }
+                Self::Instruction {
+                    mut crc_state,
+                    length,
+                } => {
+                    if input != 0x55 {
+                        return Err(Error::Parsing(ParseError::WrongInstruction(Mismatch8 {
+                            expected: 0x55,
+                            actual: input,
+                        })));
+                    }
+                    let () = crc_state.push(input);
+                    // Byte stuffing can make the packet up to a third longer than usual:
+                    let expected = const { (P::BYTES + 3) as u16 };
+                    let max_stuffing = const { (P::BYTES / 3) as u16 };
+                    if !(expected..=(expected + max_stuffing)).contains(&length) {
+                        return Err(wrong_length(length));
+                    }
+                    Self::Portions {
+                        state: P::Parser::new(()),
+                        crc_state,
+                        length,
+                        remaining: length - 3,
+                        unstuff: Unstuff::new(),
+                    }
+                }
+                Self::Portions {
+                    state,
+                    mut crc_state,
+                    length,
+                    remaining,
+                    mut unstuff,
+                } => {
+                    let () = crc_state.push(input);
+                    let remaining = remaining - 1;
+                    if unstuff.push(input) {
+                        match parse::State::push(state, input).map_err(Error::Parsing)? {
+                            parse::Status::Complete(output) => match remaining {
+                                0 => Self::FirstCrcByte {
+                                    output,
+                                    expected_crc: crc_state.collapse(),
+                                },
+                                // The last few bytes were `0xFF 0xFF 0xFD`, so expect stuffing:
+                                1 if unstuff.after_pattern => {
+                                    Self::TrailingStuffing { output, crc_state }
+                                }
+                                _ => return Err(wrong_length(length)),
+                            },
+                            parse::Status::Incomplete((state, ())) => {
+                                if remaining == 0 {
+                                    return Err(wrong_length(length));
+                                }
+                                Self::Portions {
+                                    state,
+                                    crc_state,
+                                    length,
+                                    remaining,
+                                    unstuff,
+                                }
+                            }
+                        }
+                    } else {
+                        // Stuffing: already in the CRC, but not part of the data.
+                        if remaining == 0 {
+                            return Err(wrong_length(length));
+                        }
+                        Self::Portions {
+                            state,
+                            crc_state,
+                            length,
+                            remaining,
+                            unstuff,
+                        }
+                    }
+                }
+                Self::TrailingStuffing {
+                    output,
+                    mut crc_state,
+                } => {
+                    if input != 0xFD {
+                        return Err(Error::Parsing(ParseError::WrongStuffing(Mismatch8 {
+                            expected: 0xFD,
+                            actual: input,
+                        })));
+                    }
+                    let () = crc_state.push(input);
+                    Self::FirstCrcByte {
+                        output,
+                        expected_crc: crc_state.collapse(),
+                    }
+                }
+                Self::FirstCrcByte {
+                    output,
+                    expected_crc,
+                } => Self::SecondCrcByte {
+                    output,
+                    expected_crc,
+                    first_crc_byte: input,
+                },
+                Self::SecondCrcByte {
+                    output,
+                    expected_crc,
+                    first_crc_byte,
+                } => {
+                    let actual_crc = u16::from_le_bytes([first_crc_byte, input]);
+                    if actual_crc != expected_crc {
+                        return Err(Error::Crc(Mismatch16 {
+                            expected: expected_crc,
+                            actual: actual_crc,
+                        }));
+                    }
+                    return Ok(parse::Status::Complete(output));
+                }
+            },
+            (),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{control_table::PresentPosition, packet::send::stuff},
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn fast_sync_read_roundtrip(first: u32, second: u32, hardware_error: bool) -> TestResult {
+        // Make sure stuffing shows up at least sometimes:
+        let second = if hardware_error {
+            u32::from_le_bytes([0xFF, 0xFF, 0xFD, 0x00])
+        } else {
+            second
+        };
+        let error = if hardware_error { 0x80 } else { 0x00 };
+        let mut packet = vec![0xFF, 0xFF, 0xFD, 0x00, crate::BROADCAST_ID, 0, 0, 0x55];
+        packet.extend_from_slice(&[error, 1]);
+        packet.extend_from_slice(&first.to_le_bytes());
+        // Intermediate CRC, which the parser skips:
+        packet.extend_from_slice(&[0xAB, 0xCD]);
+        packet.extend_from_slice(&[0x00, 2]);
+        packet.extend_from_slice(&second.to_le_bytes());
+        let n_portions = packet.len() - 8;
+        packet.extend_from_slice(&[0; 8]);
+        let n_stuffed = stuff(packet[8..].as_mut_ptr(), n_portions);
+        packet.truncate(8 + n_stuffed);
+        let [length_lo, length_hi] = (n_stuffed as u16 + 3).to_le_bytes();
+        packet[5] = length_lo;
+        packet[6] = length_hi;
+        let mut crc_state = Crc::new();
+        let () = crc_state.recurse_over_bytes(&packet);
+        packet.extend_from_slice(&crc_state.collapse().to_le_bytes());
+
+        let expected = [
+            Portion {
+                id: 1,
+                output: Ok(recv::Read {
+                    bytes: first.to_le_bytes(),
+                }),
+                hardware_error,
+            },
+            Portion {
+                id: 2,
+                output: Ok(recv::Read {
+                    bytes: second.to_le_bytes(),
+                }),
+                hardware_error: false,
+            },
+        ];
+        let mut state = <Fast<send::FastSyncRead<PresentPosition, 2>> as New>::new(());
+        for (i, &byte) in packet.iter().enumerate() {
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(parse::Status::Complete(output)) => {
+                    return if i + 1 != packet.len() {
+                        TestResult::error(format!("{packet:02X?} finished early at byte #{i}"))
+                    } else if output != expected {
+                        TestResult::error(format!("{packet:02X?} -> {output:?} =/= {expected:?}"))
+                    } else {
+                        TestResult::passed()
+                    };
+                }
+                Err(e) => return TestResult::error(format!("{packet:02X?} -> {e:?}")),
+            };
+        }
+        TestResult::error(format!("{packet:02X?} never finished"))
+    }
+}
@@ -113,8 +113,8 @@ impl SoftwareError {
 
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub struct Mismatch8 {
-    expected: u8,
-    actual: u8,
+    pub(crate) expected: u8,
+    pub(crate) actual: u8,
 }
 
 impl defmt::Format for Mismatch8 {
@@ -130,8 +130,8 @@ impl defmt::Format for Mismatch8 {
 
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub struct Mismatch16 {
-    expected: u16,
-    actual: u16,
+    pub(crate) expected: u16,
+    pub(crate) actual: u16,
 }
 
 impl defmt::Format for Mismatch16 {
@@ -154,6 +154,7 @@ pub enum ParseError<InstructionSpecific: defmt::Format> {
     InvalidId { id: u8 },
     WrongLength(Mismatch16),
     WrongInstruction(Mismatch8),
+    WrongStuffing(Mismatch8),
     InvalidSoftwareError(InvalidSoftwareError),
     InstructionSpecific(InstructionSpecific),
 }
@@ -177,6 +178,7 @@ impl<InstructionSpecific: defmt::Format> defmt::Format for ParseError<Instructio
             ),
             Self::WrongLength(ref e) => defmt::write!(f, "Wrong length: {}", e),
             Self::WrongInstruction(ref e) => defmt::write!(f, "Wrong instruction: {}", e),
+            Self::WrongStuffing(ref e) => defmt::write!(f, "Wrong byte stuffing: {}", e),
             Self::InvalidSoftwareError(ref e) => defmt::write!(f, "Invalid software error: {}", e),
             Self::InstructionSpecific(ref e) => {
                 defmt::write!(f, "Instruction-specific error: {}", e)
@@ -234,7 +236,54 @@ pub enum WithoutCrc<Insn: Instruction> {
         >>::Parser,
         crc_state: Crc,
         hardware_error: bool,
+        length: u16,
+        remaining: u16,
+        unstuff: Unstuff,
     },
+    TrailingStuffing {
+        id: u8,
+        output: Insn::Recv,
+        crc_state: Crc,
+        hardware_error: bool,
+    },
+}
+
+/// Tracks `0xFF 0xFF 0xFD` patterns, after which
+/// the sender inserts an extra `0xFD` that isn't part of the data.
+#[derive(Clone, Copy)]
+pub struct Unstuff {
+    consecutive_ff: u8,
+    pub(crate) after_pattern: bool,
+}
+
+impl Unstuff {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self {
+        Self {
+            consecutive_ff: 0,
+            after_pattern: false,
+        }
+    }
+
+    /// Whether this byte is data (as opposed to stuffing).
+    #[inline]
+    pub(crate) const fn push(&mut self, byte: u8) -> bool {
+        if self.after_pattern {
+            self.after_pattern = false;
+            if byte == 0xFD {
+                return false;
+            }
+        }
+        match byte {
+            0xFF => self.consecutive_ff = self.consecutive_ff.saturating_add(1),
+            0xFD if self.consecutive_ff >= 2 => {
+                self.consecutive_ff = 0;
+                self.after_pattern = true;
+            }
+            _ => self.consecutive_ff = 0,
+        }
+        true
+    }
 }
 
 impl<Insn: Instruction> WithoutCrc<Insn> {
@@ -308,9 +357,7 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
                     }
                 }
                 Self::LengthLo { id, mut crc_state } => {
-                    let () = crc_state.push(
-                        const { ((<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16) as u8 },
-                    );
+                    let () = crc_state.push(input);
                     Self::LengthHi {
                         id,
                         crc_state,
@@ -322,13 +369,7 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
                     mut crc_state,
                     length_lo,
                 } => {
-                    let () =
-                        crc_state.push(
-                            const {
-                                (((<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16) >> 8)
-                                    as u8
-                            },
-                        );
+                    let () = crc_state.push(input);
                     Self::Instruction {
                         id,
                         crc_state,
@@ -382,15 +423,16 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
                         }
                     } else {
                         // THEN, after we know it's not just short because of a misunderstood packet,
-                        // check to make sure that the length of the packet matches our expectation:
-                        if length
-                            != const { (<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16 }
-                        {
+                        // check to make sure that the length of the packet matches our expectation
+                        // (which, because of byte stuffing, can be up to a third longer than usual):
+                        let expected =
+                            const { (<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16 };
+                        let max_stuffing =
+                            const { (<Insn::Recv as crate::recv::Receive>::BYTES / 3) as u16 };
+                        if !(expected..=(expected + max_stuffing)).contains(&length) {
                             let mismatch = Mismatch16 {
                                 actual: length,
-                                expected: const {
-                                    (<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16
-                                },
+                                expected,
                             };
                             return Err(ParseError::WrongLength(mismatch));
                         }
@@ -408,6 +450,9 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
                             state,
                             crc_state,
                             hardware_error,
+                            length,
+                            remaining: length - 4,
+                            unstuff: Unstuff::new(),
                         },
                     }
                     }
@@ -445,25 +490,80 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
                     state,
                     mut crc_state,
                     hardware_error,
+                    length,
+                    remaining,
+                    mut unstuff,
                 } => {
                     let () = crc_state.push(input);
-                    match state.push(input).map_err(ParseError::InstructionSpecific)? {
-                        parse::Status::Complete(output) => {
-                            return Ok(parse::Status::Complete(WithHardwareErrorStatus {
-                                id,
-                                output: Ok(output),
-                                expected_crc: crc_state.collapse(),
-                                hardware_error,
-                            }));
+                    let wrong_length = || {
+                        ParseError::WrongLength(Mismatch16 {
+                            actual: length,
+                            expected: const { (<Insn::Recv as crate::recv::Receive>::BYTES + 4) as u16 },
+                        })
+                    };
+                    let remaining = remaining - 1;
+                    let state = if unstuff.push(input) {
+                        match state.push(input).map_err(ParseError::InstructionSpecific)? {
+                            parse::Status::Complete(output) => {
+                                return match remaining {
+                                    0 => Ok(parse::Status::Complete(WithHardwareErrorStatus {
+                                        id,
+                                        output: Ok(output),
+                                        expected_crc: crc_state.collapse(),
+                                        hardware_error,
+                                    })),
+                                    // The last few bytes were `0xFF 0xFF 0xFD`, so expect stuffing:
+                                    1 if unstuff.after_pattern => Ok(parse::Status::Incomplete((
+                                        Self::TrailingStuffing {
+                                            id,
+                                            output,
+                                            crc_state,
+                                            hardware_error,
+                                        },
+                                        (),
+                                    ))),
+                                    _ => Err(wrong_length()),
+                                };
+                            }
+                            parse::Status::Incomplete((state, _)) => state,
                         }
-                        parse::Status::Incomplete((state, _)) => Self::Parameters {
-                            id,
-                            state,
-                            crc_state,
-                            hardware_error,
-                        },
+                    } else {
+                        // Stuffing: already in the CRC, but not part of the data.
+                        state
+                    };
+                    if remaining == 0 {
+                        return Err(wrong_length());
+                    }
+                    Self::Parameters {
+                        id,
+                        state,
+                        crc_state,
+                        hardware_error,
+                        length,
+                        remaining,
+                        unstuff,
                     }
                 }
+                Self::TrailingStuffing {
+                    id,
+                    output,
+                    mut crc_state,
+                    hardware_error,
+                } => {
+                    if input != 0xFD {
+                        return Err(ParseError::WrongStuffing(Mismatch8 {
+                            expected: 0xFD,
+                            actual: input,
+                        }));
+                    }
+                    let () = crc_state.push(input);
+                    return Ok(parse::Status::Complete(WithHardwareErrorStatus {
+                        id,
+                        output: Ok(output),
+                        expected_crc: crc_state.collapse(),
+                        hardware_error,
+                    }));
+                }
             },
             (),
         )))
@@ -474,8 +574,10 @@ impl<Insn: Instruction> parse::State<u8> for WithoutCrc<Insn> {
 pub enum Error<Output, E: defmt::Format> {
     Parsing(ParseError<E>),
     Crc(Mismatch16),
-    Software(SoftwareError),
+    Software(WithId<SoftwareError>),
     Hardware(Output),
+    /// A hardware error that the status packet itself spells out (as in Protocol 1.0).
+    HardwareDetailed(Output, crate::recv::HardwareErrorStatus),
 }
 
 impl<Output, E: defmt::Format> defmt::Format for Error<Output, E> {
@@ -484,10 +586,13 @@ impl<Output, E: defmt::Format> defmt::Format for Error<Output, E> {
         match *self {
             Self::Parsing(ref e) => defmt::write!(f, "Parsing error: {}", e),
             Self::Crc(ref e) => defmt::write!(f, "CRC error: {}", e),
-            Self::Software(ref e) => defmt::write!(f, "Software error: {}", e),
+            Self::Software(WithId { id, ref output }) => {
+                defmt::write!(f, "Software error from ID {}: {}", id, output)
+            }
             Self::Hardware(_) => {
                 defmt::write!(f, "Hardware error (details require a separate request)",)
             }
+            Self::HardwareDetailed(_, ref e) => defmt::write!(f, "Hardware error: {}", e),
         }
     }
 }
@@ -570,7 +675,7 @@ impl<Insn: Instruction> parse::State<u8> for WithCrc<Insn> {
                             }));
                         }
                     }
-                    let ok = output.map_err(Error::Software)?;
+                    let ok = output.map_err(|output| Error::Software(WithId { id, output }))?;
                     let with_id = WithId { id, output: ok };
                     return if hardware_error {
                         Err(Error::Hardware(with_id))
@@ -588,6 +693,8 @@ impl<Insn: Instruction> parse::State<u8> for WithCrc<Insn> {
 pub enum PersistentError<Output> {
     Software(SoftwareError),
     Hardware(Output),
+    /// See `Error::HardwareDetailed`.
+    HardwareDetailed(Output, crate::recv::HardwareErrorStatus),
 }
 
 impl<X> PersistentError<X> {
@@ -596,6 +703,7 @@ impl<X> PersistentError<X> {
         match self {
             Self::Software(e) => PersistentError::Software(e),
             Self::Hardware(e) => PersistentError::Hardware(f(e)),
+            Self::HardwareDetailed(e, errors) => PersistentError::HardwareDetailed(f(e), errors),
         }
     }
 }
@@ -606,6 +714,7 @@ impl<Output> defmt::Format for PersistentError<Output> {
         match *self {
             Self::Software(ref e) => defmt::write!(f, "Software({})", e),
             Self::Hardware(_) => defmt::write!(f, "Hardware(..)"),
+            Self::HardwareDetailed(_, ref e) => defmt::write!(f, "Hardware({})", e),
         }
     }
 }
@@ -614,25 +723,38 @@ pub struct PersistentConfig {
     pub expected_id: u8,
 }
 
-pub struct Persistent<Insn: Instruction> {
+/// Wraps a status-packet parser (`WithCrc` or `v1::Status`),
+/// ignoring packets from other IDs and restarting after malformed ones.
+pub struct Persistent<Parser> {
     expected_id: u8,
-    parser: WithCrc<Insn>,
+    parser: Parser,
 }
 
-impl<Insn: Instruction> New for Persistent<Insn> {
+impl<Parser: New<Config = ()>> New for Persistent<Parser> {
     type Config = PersistentConfig;
 
     #[inline(always)]
     fn new(PersistentConfig { expected_id }: Self::Config) -> Self {
         Self {
             expected_id,
-            parser: <WithCrc<Insn> as New>::new(()),
+            parser: Parser::new(()),
         }
     }
 }
 
-impl<Insn: Instruction> parse::State<u8> for Persistent<Insn> {
-    type Output = Insn::Recv;
+impl<
+    Output,
+    E: defmt::Format,
+    Parser: parse::State<
+            u8,
+            Output = WithId<Output>,
+            SideEffect = (),
+            Error = Error<WithId<Output>, E>,
+            Config = (),
+        >,
+> parse::State<u8> for Persistent<Parser>
+{
+    type Output = Output;
     type SideEffect = ();
     type Error = PersistentError<Self::Output>;
 
@@ -680,7 +802,24 @@ impl<Insn: Instruction> parse::State<u8> for Persistent<Insn> {
                     (),
                 )))
             }
-            Err(Error::Software(e)) => Err(PersistentError::Software(e)),
+            Err(Error::Software(WithId {
+                id: actual_id,
+                output,
+            })) => {
+                if actual_id == expected_id {
+                    Err(PersistentError::Software(output))
+                } else {
+                    defmt::warn!(
+                        "Wrong ID (expected {} but found {}); trying again...",
+                        expected_id,
+                        actual_id
+                    );
+                    Ok(parse::Status::Incomplete((
+                        Self::new(PersistentConfig { expected_id }),
+                        (),
+                    )))
+                }
+            }
             Err(Error::Hardware(WithId {
                 id: actual_id,
                 output,
@@ -699,6 +838,80 @@ impl<Insn: Instruction> parse::State<u8> for Persistent<Insn> {
                     )))
                 }
             }
+            Err(Error::HardwareDetailed(
+                WithId {
+                    id: actual_id,
+                    output,
+                },
+                errors,
+            )) => {
+                if actual_id == expected_id {
+                    Err(PersistentError::HardwareDetailed(output, errors))
+                } else {
+                    defmt::warn!(
+                        "Wrong ID (expected {} but found {}); trying again...",
+                        expected_id,
+                        actual_id
+                    );
+                    Ok(parse::Status::Incomplete((
+                        Self::new(PersistentConfig { expected_id }),
+                        (),
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{control_table, packet::send, send::Read},
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn unstuffing_roundtrip(value: u32, pattern_at: u8) -> TestResult {
+        // Random bytes almost never contain the pattern, so plant one:
+        let mut bytes = value.to_le_bytes();
+        if let Some(window) = bytes.get_mut(usize::from(pattern_at % 4)..) {
+            for (byte, pattern) in window.iter_mut().zip([0xFF, 0xFF, 0xFD]) {
+                *byte = pattern;
+            }
+        }
+        let mut packet = vec![0xFF, 0xFF, 0xFD, 0x00, 42, 0, 0, 0x55, 0x00];
+        packet.extend_from_slice(&bytes);
+        packet.extend_from_slice(&[0; 2]);
+        let n_parameters = send::stuff(packet[9..].as_mut_ptr(), bytes.len());
+        packet.truncate(9 + n_parameters);
+        let [length_lo, length_hi] = (n_parameters as u16 + 4).to_le_bytes();
+        packet[5] = length_lo;
+        packet[6] = length_hi;
+        let mut crc_state = Crc::new();
+        let () = crc_state.recurse_over_bytes(&packet);
+        packet.extend_from_slice(&crc_state.collapse().to_le_bytes());
+
+        let mut state = <WithCrc<Read<control_table::PresentPosition>> as New>::new(());
+        for (i, &byte) in packet.iter().enumerate() {
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(parse::Status::Complete(WithId { id, output })) => {
+                    return if i + 1 != packet.len() {
+                        TestResult::error(format!("{packet:02X?} finished early at byte #{i}"))
+                    } else if (id, output.bytes) != (42, bytes) {
+                        TestResult::error(format!(
+                            "{packet:02X?} -> ID {id}, {:02X?} =/= ID 42, {bytes:02X?}",
+                            output.bytes,
+                        ))
+                    } else {
+                        TestResult::passed()
+                    };
+                }
+                Err(e) => return TestResult::error(format!("{packet:02X?} -> {e:?}")),
+            };
         }
+        TestResult::error(format!("{packet:02X?} never finished"))
     }
 }
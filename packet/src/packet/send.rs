@@ -1,4 +1,11 @@
-use crate::{Instruction, crc::Crc};
+use {
+    crate::{Instruction, crc::Crc},
+    core::mem::MaybeUninit,
+};
+
+/// Bytes before the instruction's parameters:
+/// three header bytes, one reserved, one ID, two length, and one instruction.
+pub(crate) const PREFIX_BYTES: usize = 8;
 
 #[repr(C, packed)]
 pub(crate) struct WithoutCrc<Insn: Instruction> {
@@ -37,14 +44,152 @@ impl<Insn: Instruction> WithoutCrc<Insn> {
 #[repr(C, packed)]
 pub struct WithCrc<Insn: Instruction> {
     pub(crate) without_crc: WithoutCrc<Insn>,
-    pub(crate) crc: [u8; 2],
+    // Stuffing adds at most one byte per three parameter bytes,
+    // so this always has room for any stuffing *and* the CRC:
+    pub(crate) overflow: MaybeUninit<([u8; 2], Insn)>,
+    pub(crate) size: usize,
 }
 
 impl<Insn: Instruction> WithCrc<Insn> {
+    #[inline]
+    pub(crate) const fn new(without_crc: WithoutCrc<Insn>) -> Self {
+        let mut packet = Self {
+            without_crc,
+            overflow: MaybeUninit::uninit(),
+            size: 0,
+        };
+        let n_parameters = stuff(packet.parameters(), size_of::<Insn>());
+        packet.without_crc.length = (n_parameters as u16 + 3).to_le_bytes();
+        let crc = {
+            let mut crc_state = const { WithoutCrc::<Insn>::crc_init() };
+            let () = crc_state.recurse_over_bytes(unsafe {
+                core::slice::from_raw_parts(
+                    (&raw const packet).cast::<u8>().byte_add(4),
+                    PREFIX_BYTES - 4 + n_parameters,
+                )
+            });
+            crc_state.collapse().to_le_bytes()
+        };
+        let crc_ptr = unsafe { packet.parameters().add(n_parameters) };
+        unsafe { crc_ptr.write(crc[0]) };
+        unsafe { crc_ptr.add(1).write(crc[1]) };
+        packet.size = PREFIX_BYTES + n_parameters + 2;
+        packet
+    }
+
+    #[inline(always)]
+    const fn parameters(&mut self) -> *mut u8 {
+        unsafe { (&raw mut *self).cast::<u8>().byte_add(PREFIX_BYTES) }
+    }
+
     #[inline]
     pub const fn as_buffer(&self) -> &[u8] {
         let ptr = self as *const Self as *const u8;
-        let size = const { core::mem::size_of::<Self>() };
-        unsafe { core::slice::from_raw_parts(ptr, size) }
+        unsafe { core::slice::from_raw_parts(ptr, self.size) }
+    }
+}
+
+/// Insert an extra `0xFD` after every `0xFF 0xFF 0xFD` in the `n` bytes at `ptr`,
+/// returning the number of bytes afterward.
+/// There must be enough room after `ptr` for any inserted bytes.
+#[inline]
+pub(crate) const fn stuff(ptr: *mut u8, n: usize) -> usize {
+    let mut n_stuffed = 0;
+    {
+        let mut i = 0;
+        let mut consecutive_ff = 0;
+        while i < n {
+            match unsafe { ptr.add(i).read() } {
+                0xFF => consecutive_ff += 1,
+                0xFD if consecutive_ff >= 2 => {
+                    n_stuffed += 1;
+                    consecutive_ff = 0;
+                }
+                _ => consecutive_ff = 0,
+            }
+            i += 1;
+        }
+    }
+    // Shift back-to-front so that each byte is read before it's overwritten:
+    let mut remaining = n_stuffed;
+    let mut i = n;
+    while remaining > 0 {
+        i -= 1;
+        let byte = unsafe { ptr.add(i).read() };
+        if byte == 0xFD
+            && i >= 2
+            && unsafe { ptr.add(i - 1).read() } == 0xFF
+            && unsafe { ptr.add(i - 2).read() } == 0xFF
+        {
+            unsafe { ptr.add(i + remaining).write(0xFD) };
+            remaining -= 1;
+        }
+        unsafe { ptr.add(i + remaining).write(byte) };
+    }
+    n + n_stuffed
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::{control_table, crc::Crc, send},
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn stuffing_roundtrip(bytes: u64, pattern_at: u8) -> TestResult {
+        // Random bytes almost never contain the pattern, so plant one:
+        let mut bytes = bytes.to_le_bytes();
+        if let Some(window) = bytes.get_mut(usize::from(pattern_at % 8)..) {
+            for (byte, pattern) in window.iter_mut().zip([0xFF, 0xFF, 0xFD]) {
+                *byte = pattern;
+            }
+        }
+        let packet =
+            crate::packet::new(1, send::Write::<control_table::GoalPosition, 8>::new(bytes));
+        let buffer = packet.as_buffer();
+        let Some((body, crc)) = buffer.split_last_chunk::<2>() else {
+            return TestResult::error(format!("{buffer:02X?} is too short"));
+        };
+        let mut crc_state = Crc::new();
+        let () = crc_state.recurse_over_bytes(body);
+        if crc_state.collapse() != u16::from_le_bytes(*crc) {
+            return TestResult::error(format!("{buffer:02X?} has the wrong CRC"));
+        }
+        let length = usize::from(u16::from_le_bytes([buffer[5], buffer[6]]));
+        if length != buffer.len() - 7 {
+            return TestResult::error(format!("{buffer:02X?} has the wrong length"));
+        }
+        let mut unstuffed = vec![];
+        let mut consecutive_ff = 0;
+        let mut stuffed = false;
+        for &byte in &body[10..] {
+            if stuffed {
+                stuffed = false;
+                if byte != 0xFD {
+                    return TestResult::error(format!("{buffer:02X?} is missing a stuffed byte"));
+                }
+                continue;
+            }
+            match byte {
+                0xFF => consecutive_ff += 1,
+                0xFD if consecutive_ff >= 2 => {
+                    stuffed = true;
+                    consecutive_ff = 0;
+                }
+                _ => consecutive_ff = 0,
+            }
+            unstuffed.push(byte);
+        }
+        if stuffed {
+            return TestResult::error(format!("{buffer:02X?} is missing a stuffed byte"));
+        }
+        if unstuffed != bytes {
+            return TestResult::error(format!(
+                "{buffer:02X?} -> {unstuffed:02X?} =/= {bytes:02X?}"
+            ));
+        }
+        TestResult::passed()
     }
 }
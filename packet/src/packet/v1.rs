@@ -0,0 +1,399 @@
+//! Protocol 1.0: `0xFF 0xFF`, ID, one-byte length, instruction (or error), parameters,
+//! then the inverted low byte of the sum of everything after the header.
+//! There's no byte stuffing, and addresses and lengths are one byte each.
+
+use {
+    crate::{
+        Instruction, New, control_table,
+        packet::recv::{Error, Mismatch8, Mismatch16, ParseError, SoftwareError, WithId},
+        parse, recv,
+    },
+    core::mem::MaybeUninit,
+};
+
+/// How an instruction's Protocol 2.0 parameters map onto Protocol 1.0.
+#[derive(Clone, Copy)]
+pub enum Translation {
+    /// Narrow this many leading 16-bit fields (addresses and lengths) to 8 bits,
+    /// then copy the rest unchanged.
+    Narrow(usize),
+    /// Protocol 1.0 has no equivalent.
+    Unsupported,
+}
+
+/// Protocol 1.0 pings return nothing, but every Protocol 1.0 control table
+/// starts with the same fields as a Protocol 2.0 ping response,
+/// so reading this item (parsed as `recv::Ping`) stands in for a ping.
+pub struct ModelNumberAndFirmwareVersion;
+impl control_table::Item for ModelNumberAndFirmwareVersion {
+    const ADDRESS: u16 = 0;
+    const BYTES: u16 = 3;
+    const DESCRIPTION: &str = "Model Number & Firmware Version";
+    const ACCESS: control_table::Access = control_table::Access::ReadOnly;
+    const AREA: control_table::Area = control_table::Area::Eeprom;
+    type Value = [u8; 3];
+}
+
+#[inline]
+const fn checksum(bytes: &[u8]) -> u8 {
+    let mut sum: u8 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        sum = sum.wrapping_add(bytes[i]);
+        i += 1;
+    }
+    !sum
+}
+
+#[repr(C, packed)]
+pub struct WithChecksum<Insn: Instruction> {
+    header: [u8; 2],
+    id: u8,
+    length: u8,
+    instruction: u8,
+    // Narrowing only ever shrinks parameters, so this always has room for the checksum:
+    parameters: MaybeUninit<Insn>,
+    overflow: MaybeUninit<u8>,
+    size: usize,
+}
+
+impl<Insn: Instruction> WithChecksum<Insn> {
+    /// Translate a Protocol 2.0 instruction, or `None` if Protocol 1.0 can't express it
+    /// (no equivalent instruction, or an address or length above 255).
+    #[inline]
+    pub fn new(id: u8, parameters: Insn) -> Option<Self> {
+        let Translation::Narrow(n_narrowed) = Insn::V1 else {
+            return None;
+        };
+        let mut packet = Self {
+            header: [0xFF, 0xFF],
+            id,
+            length: 0,
+            instruction: Insn::BYTE,
+            parameters: MaybeUninit::uninit(),
+            overflow: MaybeUninit::uninit(),
+            size: 0,
+        };
+        let v2: &[u8] = unsafe {
+            core::slice::from_raw_parts((&raw const parameters).cast::<u8>(), size_of::<Insn>())
+        };
+        let v1: *mut u8 = (&raw mut packet.parameters).cast();
+        let (narrowed, unchanged) = v2.split_at_checked(2 * n_narrowed)?;
+        for (i, &word) in narrowed.as_chunks::<2>().0.iter().enumerate() {
+            let byte = u8::try_from(u16::from_le_bytes(word)).ok()?;
+            unsafe { v1.add(i).write(byte) };
+        }
+        for (i, &byte) in unchanged.iter().enumerate() {
+            unsafe { v1.add(n_narrowed + i).write(byte) };
+        }
+        let n_parameters = n_narrowed + unchanged.len();
+        packet.length = u8::try_from(n_parameters + 2).ok()?;
+        let checksum = checksum(unsafe {
+            core::slice::from_raw_parts((&raw const packet).cast::<u8>().add(2), 3 + n_parameters)
+        });
+        unsafe { v1.add(n_parameters).write(checksum) };
+        packet.size = 5 + n_parameters + 1;
+        Some(packet)
+    }
+
+    #[inline]
+    pub const fn as_buffer(&self) -> &[u8] {
+        let ptr = self as *const Self as *const u8;
+        unsafe { core::slice::from_raw_parts(ptr, self.size) }
+    }
+}
+
+/// Protocol 1.0's error byte, split into what Protocol 2.0
+/// would call a software error and the bits of a hardware error.
+#[inline]
+const fn split_error(byte: u8) -> (Option<SoftwareError>, u8) {
+    let software_error = if byte & 0x40 != 0 {
+        Some(SoftwareError::InstructionError)
+    } else if byte & 0x10 != 0 {
+        Some(SoftwareError::CrcError)
+    } else if byte & 0x08 != 0 {
+        Some(SoftwareError::DataRangeError)
+    } else if byte & 0x02 != 0 {
+        // Angle limit:
+        Some(SoftwareError::DataLimitError)
+    } else {
+        None
+    };
+    // Input voltage, overheating, and overload,
+    // in the same bits as Protocol 2.0's `HardwareErrorStatus`:
+    let hardware_errors = byte & 0x25;
+    (software_error, hardware_errors)
+}
+
+pub enum Status<Recv: recv::Receive> {
+    Header1,
+    Header2,
+    Id,
+    Length {
+        id: u8,
+    },
+    ErrorByte {
+        id: u8,
+        length: u8,
+        sum: u8,
+    },
+    Parameters {
+        id: u8,
+        state: <Recv::Parser as parse::MaybeParse<u8, Recv>>::Parser,
+        sum: u8,
+        hardware_errors: u8,
+    },
+    SkipParameters {
+        id: u8,
+        sum: u8,
+        software_error: SoftwareError,
+        hardware_errors: u8,
+        remaining: u8,
+    },
+    Checksum {
+        id: u8,
+        output: Result<Recv, SoftwareError>,
+        sum: u8,
+        hardware_errors: u8,
+    },
+}
+
+impl<Recv: recv::Receive> New for Status<Recv> {
+    type Config = ();
+
+    #[inline(always)]
+    fn new((): ()) -> Self {
+        Self::Header1
+    }
+}
+
+impl<Recv: recv::Receive> parse::State<u8> for Status<Recv> {
+    type Output = WithId<Recv>;
+    type SideEffect = ();
+    type Error = Error<
+        Self::Output,
+        <<Recv::Parser as parse::MaybeParse<u8, Recv>>::Parser as parse::State<u8>>::Error,
+    >;
+
+    #[inline]
+    #[expect(clippy::too_many_lines, reason = "Lots of cases in a single match.")]
+    fn push(
+        self,
+        input: u8,
+    ) -> Result<parse::Status<Self::Output, (Self, Self::SideEffect)>, Self::Error> {
+        macro_rules! expect {
+            ($byte:expr, $err:ident, $next:ident) => {
+                if input == $byte {
+                    Self::$next
+                } else {
+                    return Err(Error::Parsing(ParseError::$err(Mismatch8 {
+                        expected: $byte,
+                        actual: input,
+                    })));
+                }
+            };
+        }
+
+        Ok(parse::Status::Incomplete((
+            match self {
+                Self::Header1 => expect!(0xFF, WrongFirstHeaderByte, Header2),
+                Self::Header2 => expect!(0xFF, WrongSecondHeaderByte, Id),
+                Self::Id => {
+                    if !(crate::MIN_ID..=crate::MAX_ID).contains(&input) {
+                        return Err(Error::Parsing(ParseError::InvalidId { id: input }));
+                    }
+                    Self::Length { id: input }
+                }
+                Self::Length { id } => Self::ErrorByte {
+                    id,
+                    length: input,
+                    sum: id.wrapping_add(input),
+                },
+                Self::ErrorByte { id, length, sum } => {
+                    let sum = sum.wrapping_add(input);
+                    let (software_error, hardware_errors) = split_error(input);
+                    if let Some(software_error) = software_error {
+                        // Parameters (if any) mean nothing after a software error:
+                        match length.checked_sub(2) {
+                            None => {
+                                return Err(Error::Parsing(ParseError::WrongLength(Mismatch16 {
+                                    expected: 2,
+                                    actual: length.into(),
+                                })));
+                            }
+                            Some(0) => Self::Checksum {
+                                id,
+                                output: Err(software_error),
+                                sum,
+                                hardware_errors,
+                            },
+                            Some(remaining) => Self::SkipParameters {
+                                id,
+                                sum,
+                                software_error,
+                                hardware_errors,
+                                remaining,
+                            },
+                        }
+                    } else {
+                        let expected = const { Recv::BYTES as u16 + 2 };
+                        if u16::from(length) != expected {
+                            return Err(Error::Parsing(ParseError::WrongLength(Mismatch16 {
+                                expected,
+                                actual: length.into(),
+                            })));
+                        }
+                        match <Recv::Parser as parse::MaybeParse<u8, Recv>>::init() {
+                            parse::Status::Complete(output) => Self::Checksum {
+                                id,
+                                output: Ok(output),
+                                sum,
+                                hardware_errors,
+                            },
+                            parse::Status::Incomplete(state) => Self::Parameters {
+                                id,
+                                state,
+                                sum,
+                                hardware_errors,
+                            },
+                        }
+                    }
+                }
+                Self::Parameters {
+                    id,
+                    state,
+                    sum,
+                    hardware_errors,
+                } => {
+                    let sum = sum.wrapping_add(input);
+                    match state
+                        .push(input)
+                        .map_err(|e| Error::Parsing(ParseError::InstructionSpecific(e)))?
+                    {
+                        parse::Status::Complete(output) => Self::Checksum {
+                            id,
+                            output: Ok(output),
+                            sum,
+                            hardware_errors,
+                        },
+                        parse::Status::Incomplete((state, _)) => Self::Parameters {
+                            id,
+                            state,
+                            sum,
+                            hardware_errors,
+                        },
+                    }
+                }
+                Self::SkipParameters {
+                    id,
+                    sum,
+                    software_error,
+                    hardware_errors,
+                    remaining,
+                } => {
+                    let sum = sum.wrapping_add(input);
+                    match remaining - 1 {
+                        0 => Self::Checksum {
+                            id,
+                            output: Err(software_error),
+                            sum,
+                            hardware_errors,
+                        },
+                        remaining => Self::SkipParameters {
+                            id,
+                            sum,
+                            software_error,
+                            hardware_errors,
+                            remaining,
+                        },
+                    }
+                }
+                Self::Checksum {
+                    id,
+                    output,
+                    sum,
+                    hardware_errors,
+                } => {
+                    let expected = !sum;
+                    if input != expected {
+                        return Err(Error::Crc(Mismatch16 {
+                            expected: expected.into(),
+                            actual: input.into(),
+                        }));
+                    }
+                    let output = output.map_err(|output| Error::Software(WithId { id, output }))?;
+                    let with_id = WithId { id, output };
+                    return if hardware_errors != 0 {
+                        Err(Error::HardwareDetailed(
+                            with_id,
+                            recv::HardwareErrorStatus::parse_byte(hardware_errors),
+                        ))
+                    } else {
+                        Ok(parse::Status::Complete(with_id))
+                    };
+                }
+            },
+            (),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{control_table, send},
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+    };
+
+    #[quickcheck]
+    fn read_roundtrip(id: u8, value: u16) -> TestResult {
+        let id = id % (crate::MAX_ID + 1);
+        let request =
+            WithChecksum::new(id, send::Read::<control_table::ax::PresentPosition>::new());
+        let Some(request) = request else {
+            return TestResult::error("Couldn't translate `Read` to Protocol 1.0");
+        };
+        // Narrowed from four parameter bytes to two:
+        let expected_request = {
+            let mut bytes = vec![0xFF, 0xFF, id, 4, 0x02, 36, 2];
+            bytes.push(checksum(&bytes[2..]));
+            bytes
+        };
+        if request.as_buffer() != expected_request {
+            return TestResult::error(format!(
+                "{:02X?} =/= {expected_request:02X?}",
+                request.as_buffer()
+            ));
+        }
+
+        let mut response = vec![0xFF, 0xFF, id, 4, 0x00];
+        response.extend_from_slice(&value.to_le_bytes());
+        response.push(checksum(&response[2..]));
+        let mut state = <Status<recv::Read<2>> as New>::new(());
+        for (i, &byte) in response.iter().enumerate() {
+            state = match parse::State::push(state, byte) {
+                Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                Ok(parse::Status::Complete(WithId {
+                    id: actual_id,
+                    output,
+                })) => {
+                    return if i + 1 != response.len() {
+                        TestResult::error(format!("{response:02X?} finished early at byte #{i}"))
+                    } else if (actual_id, output.bytes) != (id, value.to_le_bytes()) {
+                        TestResult::error(format!(
+                            "{response:02X?} -> ID {actual_id}, {:02X?} =/= ID {id}, {:02X?}",
+                            output.bytes,
+                            value.to_le_bytes(),
+                        ))
+                    } else {
+                        TestResult::passed()
+                    };
+                }
+                Err(e) => return TestResult::error(format!("{response:02X?} -> {e:?}")),
+            };
+        }
+        TestResult::error(format!("{response:02X?} never finished"))
+    }
+}
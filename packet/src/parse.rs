@@ -196,7 +196,7 @@ mod test {
     fn parse_u16(i: u16) -> TestResult {
         let little_endian = i.to_le_bytes();
         let mut s = stream::WithLog(stream::Loop::new(&little_endian));
-        let mut state = ParseU16::init();
+        let mut state = ParseU16::new(());
         loop {
             let input = match pin!(s.next())
                 .poll(&mut const { task::Context::from_waker(task::Waker::noop()) })
@@ -36,6 +36,11 @@ pub type RegWrite = ();
 pub type Action = ();
 pub type FactoryReset = ();
 pub type Reboot = ();
+pub type Clear = ();
+pub type ControlTableBackup = ();
+pub type SyncRead<const BYTES: usize> = Read<BYTES>;
+pub type SyncWrite = ();
+pub type BulkWrite = ();
 
 #[non_exhaustive]
 #[cfg_attr(test, derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd,))]
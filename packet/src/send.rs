@@ -5,7 +5,11 @@
 
 use core::marker::PhantomData;
 
-use crate::{Instruction, control_table, recv};
+use crate::{
+    Instruction, control_table,
+    list::{Cons, Nil},
+    packet, recv,
+};
 
 #[repr(C, packed)]
 #[derive(defmt::Format)]
@@ -20,6 +24,7 @@ impl Ping {
 impl Instruction for Ping {
     const BYTE: u8 = 0x01;
     const GERUND: &str = "Pinging";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(0);
     type Recv = recv::Ping;
 }
 
@@ -41,7 +46,7 @@ where
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            address: (Address::ADDRESS as u16).to_le_bytes(),
+            address: Address::ADDRESS.to_le_bytes(),
             length: Address::BYTES.to_le_bytes(),
             _phantom: PhantomData,
         }
@@ -53,32 +58,35 @@ where
 {
     const BYTE: u8 = 0x02;
     const GERUND: &str = "Reading";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(2);
     type Recv = recv::Read<{ Address::BYTES as usize }>;
 }
 
 #[repr(C, packed)]
-pub struct Write<Address: control_table::Item, const BYTES: usize> {
+pub struct Write<Address: control_table::Writable, const BYTES: usize> {
     address: [u8; 2],
     bytes: [u8; BYTES],
     _phantom: PhantomData<Address>,
 }
-impl<Address: control_table::Item, const BYTES: usize> Write<Address, BYTES> {
+impl<Address: control_table::Writable, const BYTES: usize> Write<Address, BYTES> {
     #[inline]
     #[must_use]
     pub const fn new(bytes: [u8; BYTES]) -> Self {
+        let () = Address::READ_WRITE;
         Self {
-            address: (Address::ADDRESS as u16).to_le_bytes(),
+            address: Address::ADDRESS.to_le_bytes(),
             bytes,
             _phantom: PhantomData,
         }
     }
 }
-impl<Address: control_table::Item, const BYTES: usize> Instruction for Write<Address, BYTES> {
+impl<Address: control_table::Writable, const BYTES: usize> Instruction for Write<Address, BYTES> {
     const BYTE: u8 = 0x03;
     const GERUND: &str = "Writing";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(1);
     type Recv = ();
 }
-impl<Address: control_table::Item, const BYTES: usize> defmt::Format for Write<Address, BYTES> {
+impl<Address: control_table::Writable, const BYTES: usize> defmt::Format for Write<Address, BYTES> {
     #[inline]
     fn format(&self, f: defmt::Formatter) {
         defmt::write!(f, "Write {{ address: {}, bytes: [ ", Address::DESCRIPTION);
@@ -91,37 +99,33 @@ impl<Address: control_table::Item, const BYTES: usize> defmt::Format for Write<A
 }
 
 #[repr(C, packed)]
-pub struct RegWrite<Address: control_table::Item>
-where
-    [(); Address::BYTES as usize]:,
-{
+pub struct RegWrite<Address: control_table::Writable, const BYTES: usize> {
     address: [u8; 2],
-    bytes: [u8; Address::BYTES as usize],
+    bytes: [u8; BYTES],
+    _phantom: PhantomData<Address>,
 }
-impl<Address: control_table::Item> RegWrite<Address>
-where
-    [(); Address::BYTES as usize]:,
-{
+impl<Address: control_table::Writable, const BYTES: usize> RegWrite<Address, BYTES> {
     #[inline]
     #[must_use]
-    pub const fn new(bytes: [u8; Address::BYTES as usize]) -> Self {
+    pub const fn new(bytes: [u8; BYTES]) -> Self {
+        let () = Address::READ_WRITE;
         Self {
-            address: (Address::ADDRESS as u16).to_le_bytes(),
+            address: Address::ADDRESS.to_le_bytes(),
             bytes,
+            _phantom: PhantomData,
         }
     }
 }
-impl<Address: control_table::Item> Instruction for RegWrite<Address>
-where
-    [(); Address::BYTES as usize]:,
+impl<Address: control_table::Writable, const BYTES: usize> Instruction
+    for RegWrite<Address, BYTES>
 {
     const BYTE: u8 = 0x04;
     const GERUND: &str = "Register-writing";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(1);
     type Recv = ();
 }
-impl<Address: control_table::Item> defmt::Format for RegWrite<Address>
-where
-    [(); Address::BYTES as usize]:,
+impl<Address: control_table::Writable, const BYTES: usize> defmt::Format
+    for RegWrite<Address, BYTES>
 {
     #[inline]
     fn format(&self, f: defmt::Formatter) {
@@ -131,8 +135,8 @@ where
             Address::DESCRIPTION
         );
         let byte: *const u8 = (&raw const self.bytes).cast();
-        for i in 0..Address::BYTES {
-            defmt::write!(f, "x{=u8:X}, ", unsafe { byte.add(usize::from(i)).read() });
+        for i in 0..BYTES {
+            defmt::write!(f, "x{=u8:X}, ", unsafe { byte.add(i).read() });
         }
         let () = defmt::write!(f, "] }}");
     }
@@ -151,17 +155,53 @@ impl Action {
 impl Instruction for Action {
     const BYTE: u8 = 0x05;
     const GERUND: &str = "Sending action";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(0);
     type Recv = ();
 }
 
+#[repr(u8)]
+#[derive(Clone, Copy, defmt::Format)]
+pub enum Reset {
+    /// Everything, including the ID (to 1) and baud rate (to 57,600).
+    All = 0xFF,
+    /// Everything but the ID (the baud rate still resets to 57,600).
+    AllButId = 0x01,
+    /// Everything but the ID and baud rate.
+    AllButIdAndBaud = 0x02,
+}
+
+impl Reset {
+    /// ID to which an actuator formerly at `id` will respond after this reset.
+    #[inline]
+    #[must_use]
+    pub const fn id_after(self, id: u8) -> u8 {
+        match self {
+            Self::All => 1,
+            Self::AllButId | Self::AllButIdAndBaud => id,
+        }
+    }
+
+    /// Baud rate at which an actuator formerly at `baud` will respond after this reset.
+    #[inline]
+    #[must_use]
+    pub const fn baud_after(self, baud: u32) -> u32 {
+        match self {
+            Self::All | Self::AllButId => 57_600,
+            Self::AllButIdAndBaud => baud,
+        }
+    }
+}
+
 #[repr(C, packed)]
 #[derive(defmt::Format)]
-pub struct FactoryReset;
+pub struct FactoryReset {
+    scope: u8,
+}
 impl FactoryReset {
     #[inline]
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub const fn new(scope: Reset) -> Self {
+        Self { scope: scope as u8 }
     }
 }
 impl Instruction for FactoryReset {
@@ -183,5 +223,437 @@ impl Reboot {
 impl Instruction for Reboot {
     const BYTE: u8 = 0x08;
     const GERUND: &str = "Rebooting";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(0);
+    type Recv = ();
+}
+
+#[repr(C, packed)]
+#[derive(defmt::Format)]
+pub struct Clear {
+    option: u8,
+    signature: [u8; 4],
+}
+impl Clear {
+    /// Reset the multi-turn revolution count, leaving only the position within one turn.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            option: 0x01,
+            // Fixed signature that keeps corrupted packets from clearing anything:
+            signature: [0x44, 0x58, 0x4C, 0x22],
+        }
+    }
+}
+impl Instruction for Clear {
+    const BYTE: u8 = 0x10;
+    const GERUND: &str = "Clearing";
+    type Recv = recv::Clear;
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, defmt::Format)]
+pub enum Backup {
+    /// Copy the current control table into the backup area.
+    Store = 0x01,
+    /// Overwrite the current control table with the backup area.
+    Restore = 0x02,
+}
+
+#[repr(C, packed)]
+#[derive(defmt::Format)]
+pub struct ControlTableBackup {
+    operation: u8,
+    signature: [u8; 4],
+}
+impl ControlTableBackup {
+    #[inline]
+    #[must_use]
+    pub const fn new(operation: Backup) -> Self {
+        Self {
+            operation: operation as u8,
+            // "CTRL":
+            signature: [0x43, 0x54, 0x52, 0x4C],
+        }
+    }
+}
+impl Instruction for ControlTableBackup {
+    const BYTE: u8 = 0x20;
+    const GERUND: &str = "Backing up";
+    type Recv = recv::ControlTableBackup;
+}
+
+#[repr(C, packed)]
+pub struct SyncRead<Address: control_table::Item, const N: usize>
+where
+    [(); Address::BYTES as usize]:,
+{
+    address: [u8; 2],
+    length: [u8; 2],
+    ids: [u8; N],
+    _phantom: PhantomData<Address>,
+}
+impl<Address: control_table::Item, const N: usize> SyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(ids: [u8; N]) -> Self {
+        Self {
+            address: Address::ADDRESS.to_le_bytes(),
+            length: Address::BYTES.to_le_bytes(),
+            ids,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<Address: control_table::Item, const N: usize> Instruction for SyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const BYTE: u8 = 0x82;
+    const GERUND: &str = "Synchronized-reading";
+    type Recv = recv::SyncRead<{ Address::BYTES as usize }>;
+}
+impl<Address: control_table::Item, const N: usize> defmt::Format for SyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "SyncRead {{ address: {}, ids: [ ", Address::DESCRIPTION);
+        let id: *const u8 = (&raw const self.ids).cast();
+        for i in 0..N {
+            defmt::write!(f, "{}, ", unsafe { id.add(i).read() });
+        }
+        let () = defmt::write!(f, "] }}");
+    }
+}
+
+#[repr(C, packed)]
+pub struct FastSyncRead<Address: control_table::Item, const N: usize>
+where
+    [(); Address::BYTES as usize]:,
+{
+    address: [u8; 2],
+    length: [u8; 2],
+    pub(crate) ids: [u8; N],
+    _phantom: PhantomData<Address>,
+}
+impl<Address: control_table::Item, const N: usize> FastSyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(ids: [u8; N]) -> Self {
+        Self {
+            address: Address::ADDRESS.to_le_bytes(),
+            length: Address::BYTES.to_le_bytes(),
+            ids,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<Address: control_table::Item, const N: usize> Instruction for FastSyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const BYTE: u8 = 0x8A;
+    const GERUND: &str = "Fast-synchronized-reading";
+    // All IDs share one status packet, parsed by `packet::fast::Fast`:
     type Recv = ();
 }
+impl<Address: control_table::Item, const N: usize> defmt::Format for FastSyncRead<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "FastSyncRead {{ address: {}, ids: [ ",
+            Address::DESCRIPTION
+        );
+        let id: *const u8 = (&raw const self.ids).cast();
+        for i in 0..N {
+            defmt::write!(f, "{}, ", unsafe { id.add(i).read() });
+        }
+        let () = defmt::write!(f, "] }}");
+    }
+}
+
+#[repr(C, packed)]
+pub struct SyncWriteEntry<Address: control_table::Writable>
+where
+    [(); Address::BYTES as usize]:,
+{
+    id: u8,
+    bytes: [u8; Address::BYTES as usize],
+}
+impl<Address: control_table::Writable> SyncWriteEntry<Address>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(id: u8, bytes: [u8; Address::BYTES as usize]) -> Self {
+        let () = Address::READ_WRITE;
+        Self { id, bytes }
+    }
+}
+
+#[repr(C, packed)]
+pub struct SyncWrite<Address: control_table::Writable, const N: usize>
+where
+    [(); Address::BYTES as usize]:,
+{
+    address: [u8; 2],
+    length: [u8; 2],
+    entries: [SyncWriteEntry<Address>; N],
+}
+impl<Address: control_table::Writable, const N: usize> SyncWrite<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(entries: [SyncWriteEntry<Address>; N]) -> Self {
+        Self {
+            address: Address::ADDRESS.to_le_bytes(),
+            length: Address::BYTES.to_le_bytes(),
+            entries,
+        }
+    }
+}
+impl<Address: control_table::Writable, const N: usize> Instruction for SyncWrite<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    const BYTE: u8 = 0x83;
+    const GERUND: &str = "Synchronized-writing";
+    const V1: packet::v1::Translation = packet::v1::Translation::Narrow(2);
+    type Recv = recv::SyncWrite;
+}
+impl<Address: control_table::Writable, const N: usize> defmt::Format for SyncWrite<Address, N>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SyncWrite {{ address: {}, entries: [ ",
+            Address::DESCRIPTION
+        );
+        let entry: *const SyncWriteEntry<Address> = (&raw const self.entries).cast();
+        for i in 0..N {
+            let entry = unsafe { entry.add(i) };
+            let id = unsafe { (&raw const (*entry).id).read() };
+            defmt::write!(f, "{{ id: {}, bytes: [ ", id);
+            let byte: *const u8 = unsafe { (&raw const (*entry).bytes).cast() };
+            for j in 0..Address::BYTES {
+                defmt::write!(f, "x{=u8:X}, ", unsafe { byte.add(usize::from(j)).read() });
+            }
+            defmt::write!(f, "] }}, ");
+        }
+        let () = defmt::write!(f, "] }}");
+    }
+}
+
+#[repr(C, packed)]
+pub struct BulkReadEntry<Address: control_table::Item> {
+    id: u8,
+    address: [u8; 2],
+    length: [u8; 2],
+    _phantom: PhantomData<Address>,
+}
+impl<Address: control_table::Item> BulkReadEntry<Address> {
+    #[inline]
+    #[must_use]
+    pub const fn new(id: u8) -> Self {
+        Self {
+            id,
+            address: Address::ADDRESS.to_le_bytes(),
+            length: Address::BYTES.to_le_bytes(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn id(&self) -> u8 {
+        self.id
+    }
+}
+impl<Address: control_table::Item> Clone for BulkReadEntry<Address> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Address: control_table::Item> Copy for BulkReadEntry<Address> {}
+impl<Address: control_table::Item> defmt::Format for BulkReadEntry<Address> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "{{ id: {}, address: {} }}",
+            self.id(),
+            Address::DESCRIPTION
+        )
+    }
+}
+
+/// A `list::Cons` list of `BulkReadEntry`s.
+pub trait BulkReadEntries: Copy + defmt::Format {}
+impl BulkReadEntries for Nil {}
+impl<Address: control_table::Item, Tail: BulkReadEntries> BulkReadEntries
+    for Cons<BulkReadEntry<Address>, Tail>
+{
+}
+
+#[repr(C, packed)]
+pub struct BulkRead<Entries: BulkReadEntries> {
+    entries: Entries,
+}
+impl<Entries: BulkReadEntries> BulkRead<Entries> {
+    #[inline]
+    #[must_use]
+    pub const fn new(entries: Entries) -> Self {
+        Self { entries }
+    }
+}
+impl<Entries: BulkReadEntries> Instruction for BulkRead<Entries> {
+    const BYTE: u8 = 0x92;
+    const GERUND: &str = "Bulk-reading";
+    // Each ID sends its own status packet, parsed as a response to `Read`:
+    type Recv = ();
+}
+impl<Entries: BulkReadEntries> defmt::Format for BulkRead<Entries> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        let entries = self.entries;
+        defmt::write!(f, "BulkRead {{ entries: [ {}] }}", entries)
+    }
+}
+
+#[repr(C, packed)]
+pub struct FastBulkRead<Entries: BulkReadEntries> {
+    pub(crate) entries: Entries,
+}
+impl<Entries: BulkReadEntries> FastBulkRead<Entries> {
+    #[inline]
+    #[must_use]
+    pub const fn new(entries: Entries) -> Self {
+        Self { entries }
+    }
+}
+impl<Entries: BulkReadEntries> Instruction for FastBulkRead<Entries> {
+    const BYTE: u8 = 0x9A;
+    const GERUND: &str = "Fast-bulk-reading";
+    // All IDs share one status packet, parsed by `packet::fast::Fast`:
+    type Recv = ();
+}
+impl<Entries: BulkReadEntries> defmt::Format for FastBulkRead<Entries> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        let entries = self.entries;
+        defmt::write!(f, "FastBulkRead {{ entries: [ {}] }}", entries)
+    }
+}
+
+#[repr(C, packed)]
+pub struct BulkWriteEntry<Address: control_table::Writable>
+where
+    [(); Address::BYTES as usize]:,
+{
+    id: u8,
+    address: [u8; 2],
+    length: [u8; 2],
+    bytes: [u8; Address::BYTES as usize],
+}
+impl<Address: control_table::Writable> BulkWriteEntry<Address>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    #[must_use]
+    pub fn new(id: u8, value: Address::Value) -> Self {
+        use control_table::value::Value as _;
+        let () = Address::READ_WRITE;
+        Self {
+            id,
+            address: Address::ADDRESS.to_le_bytes(),
+            length: Address::BYTES.to_le_bytes(),
+            bytes: value.to_le_bytes(),
+        }
+    }
+}
+impl<Address: control_table::Writable> Clone for BulkWriteEntry<Address>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Address: control_table::Writable> Copy for BulkWriteEntry<Address> where
+    [(); Address::BYTES as usize]:
+{
+}
+impl<Address: control_table::Writable> defmt::Format for BulkWriteEntry<Address>
+where
+    [(); Address::BYTES as usize]:,
+{
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        let id = self.id;
+        defmt::write!(
+            f,
+            "{{ id: {}, address: {}, bytes: [ ",
+            id,
+            Address::DESCRIPTION
+        );
+        let byte: *const u8 = (&raw const self.bytes).cast();
+        for i in 0..Address::BYTES {
+            defmt::write!(f, "x{=u8:X}, ", unsafe { byte.add(usize::from(i)).read() });
+        }
+        let () = defmt::write!(f, "] }}");
+    }
+}
+
+/// A `list::Cons` list of `BulkWriteEntry`s.
+pub trait BulkWriteEntries: Copy + defmt::Format {}
+impl BulkWriteEntries for Nil {}
+impl<Address: control_table::Writable, Tail: BulkWriteEntries> BulkWriteEntries
+    for Cons<BulkWriteEntry<Address>, Tail>
+where
+    [(); Address::BYTES as usize]:,
+{
+}
+
+#[repr(C, packed)]
+pub struct BulkWrite<Entries: BulkWriteEntries> {
+    entries: Entries,
+}
+impl<Entries: BulkWriteEntries> BulkWrite<Entries> {
+    #[inline]
+    #[must_use]
+    pub const fn new(entries: Entries) -> Self {
+        Self { entries }
+    }
+}
+impl<Entries: BulkWriteEntries> Instruction for BulkWrite<Entries> {
+    const BYTE: u8 = 0x93;
+    const GERUND: &str = "Bulk-writing";
+    type Recv = recv::BulkWrite;
+}
+impl<Entries: BulkWriteEntries> defmt::Format for BulkWrite<Entries> {
+    #[inline]
+    fn format(&self, f: defmt::Formatter) {
+        let entries = self.entries;
+        defmt::write!(f, "BulkWrite {{ entries: [ {}] }}", entries)
+    }
+}
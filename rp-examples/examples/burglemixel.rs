@@ -4,7 +4,6 @@
 
 use {
     defmt_rtt as _,
-    dxl_driver::comm::Comm as _,
     dxl_packet::stream::Stream as _,
     dxl_rp::{Comm, serial::RecvError},
     embassy_executor::Spawner,
@@ -344,7 +343,7 @@ async fn trigger_recording<
                 [b1, b2, b3]
             };
 
-            let mut stream = match dxl_comm.comm(packet_from_usb).await {
+            let mut stream = match dxl_comm.forward(packet_from_usb).await {
                 Ok(ok) => {
                     defmt::debug!("Sent `{:X}` via UART", packet_from_usb);
                     ok
@@ -589,7 +588,7 @@ async fn trigger_playback<
         let ms = header & 0xFF_FF_FF;
         let () = Timer::at(start_instant + Duration::from_millis(u64::from(ms))).await;
 
-        let mut stream = match dxl_comm.comm(packet_to_dxl).await {
+        let mut stream = match dxl_comm.forward(packet_to_dxl).await {
             Ok(ok) => {
                 defmt::debug!("Sent `{:X}` via UART", packet_to_dxl);
                 ok
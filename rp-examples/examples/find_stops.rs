@@ -6,7 +6,7 @@ use {
     core::num::NonZeroU32,
     defmt_rtt as _,
     dxl_driver::{bus::Bus, comm::Comm, mutex::Mutex as _},
-    dxl_packet::recv::Read,
+    dxl_packet::control_table::value::OperatingMode,
     dxl_rp::serial,
     embassy_executor::Spawner,
     embassy_rp::{
@@ -49,8 +49,7 @@ const POSITION_TOLERANCE: i16 = 8;
 async fn pos<C: Comm>(bus: &mut Bus<C>, id: u8) -> i16 {
     loop {
         match bus.read_present_position(id).await {
-            Ok(Read { bytes }) => {
-                let pos = i32::from_le_bytes(bytes);
+            Ok(pos) => {
                 let pos = match i16::try_from(pos) {
                     Ok(ok) => ok,
                     Err(e) => {
@@ -79,12 +78,11 @@ async fn position_when_stopped<C: Comm>(bus: &mut Bus<C>, id: u8, increasing: bo
         match bus
             .write_goal_current(
                 id,
-                (if increasing {
+                if increasing {
                     SAFE_CURRENT
                 } else {
                     -SAFE_CURRENT
-                })
-                .to_le_bytes(),
+                },
             )
             .await
         {
@@ -191,7 +189,7 @@ async fn main(spawner: Spawner) {
         for id in 0..dxl_packet::N_IDS {
             log::debug!("Pinging {}...", id);
             defmt::debug!("Pinging {}...", id);
-            match bus.write_torque_enable(id, [0]).await {
+            match bus.write_torque_enable(id, false).await {
                 Ok(()) => {
                     defmt::info!("    --> ID {} responded!", id);
                     log::info!("    --> ID {id} responded!");
@@ -229,7 +227,7 @@ async fn main(spawner: Spawner) {
         'reboot: loop {
             match bus.reboot(id).await {
                 Ok(()) => loop {
-                    match bus.write_torque_enable(id, [0]).await {
+                    match bus.write_torque_enable(id, false).await {
                         Ok(()) => break 'reboot,
                         Err(dxl_driver::bus::Error::Io(dxl_driver::IoError::Recv(
                             serial::RecvError::TimedOut(_),
@@ -259,7 +257,7 @@ async fn main(spawner: Spawner) {
 
         'operating_mode: loop {
             // Use *current-control mode*, not position control:
-            match bus.write_operating_mode(id, [0]).await {
+            match bus.write_operating_mode(id, OperatingMode::Current).await {
                 Ok(()) => break 'operating_mode,
                 Err(e) => {
                     defmt::error!(
@@ -277,7 +275,7 @@ async fn main(spawner: Spawner) {
         /*
         'velocity: loop {
         match bus
-            .write_profile_velocity(id, SAFE_PROFILE_VELOCITY.to_le_bytes())
+            .write_profile_velocity(id, SAFE_PROFILE_VELOCITY)
             .await
         {
             Ok(()) => break 'velocity,
@@ -296,7 +294,7 @@ async fn main(spawner: Spawner) {
 
         'acceleration: loop {
         match bus
-            .write_profile_acceleration(id, SAFE_PROFILE_ACCELERATION.to_le_bytes())
+            .write_profile_acceleration(id, SAFE_PROFILE_ACCELERATION)
             .await
         {
             Ok(()) => break 'acceleration,
@@ -315,7 +313,7 @@ async fn main(spawner: Spawner) {
         */
 
         'torque: loop {
-            match bus.write_torque_enable(id, [1]).await {
+            match bus.write_torque_enable(id, true).await {
                 Ok(()) => break 'torque,
                 Err(e) => {
                     defmt::error!(
@@ -333,7 +331,7 @@ async fn main(spawner: Spawner) {
         log::info!("ID {id} stopped decreasing at {min}");
 
         'torque: loop {
-            match bus.write_torque_enable(id, [0]).await {
+            match bus.write_torque_enable(id, false).await {
                 Ok(()) => break 'torque,
                 Err(e) => {
                     defmt::error!(
@@ -347,7 +345,7 @@ async fn main(spawner: Spawner) {
         }
         let () = Timer::after(Duration::from_millis(500)).await;
         'torque: loop {
-            match bus.write_torque_enable(id, [1]).await {
+            match bus.write_torque_enable(id, true).await {
                 Ok(()) => break 'torque,
                 Err(e) => {
                     defmt::error!(
@@ -373,7 +371,7 @@ async fn main(spawner: Spawner) {
         log::info!("ID {id} stops set to {stop:?}");
 
         'torque: loop {
-            match bus.write_torque_enable(id, [0]).await {
+            match bus.write_torque_enable(id, false).await {
                 Ok(()) => break 'torque,
                 Err(e) => {
                     defmt::error!(
@@ -400,7 +398,7 @@ async fn main(spawner: Spawner) {
 
         // Back to position control mode:
         'operating_mode: loop {
-            match bus.write_operating_mode(id, [3]).await {
+            match bus.write_operating_mode(id, OperatingMode::Position).await {
                 Ok(()) => break 'operating_mode,
                 Err(e) => {
                     defmt::error!(
@@ -417,7 +415,7 @@ async fn main(spawner: Spawner) {
 
         'velocity: loop {
             match bus
-                .write_profile_velocity(id, PROFILE_VELOCITY.to_le_bytes())
+                .write_profile_velocity(id, PROFILE_VELOCITY)
                 .await
             {
                 Ok(()) => break 'velocity,
@@ -434,7 +432,7 @@ async fn main(spawner: Spawner) {
         }
         'acceleration: loop {
             match bus
-                .write_profile_acceleration(id, PROFILE_ACCELERATION.to_le_bytes())
+                .write_profile_acceleration(id, PROFILE_ACCELERATION)
                 .await
             {
                 Ok(()) => break 'acceleration,
@@ -452,7 +450,7 @@ async fn main(spawner: Spawner) {
             }
         }
         'torque_on: loop {
-            match bus.write_torque_enable(id, [1]).await {
+            match bus.write_torque_enable(id, true).await {
                 Ok(()) => break 'torque_on,
                 Err(e) => {
                     defmt::error!("Error enabling torque for ID {}: {}", id, e);
@@ -462,7 +460,7 @@ async fn main(spawner: Spawner) {
         }
         'send: loop {
             match bus
-                .write_goal_position(id, i32::from(min).to_le_bytes())
+                .write_goal_position(id, i32::from(min))
                 .await
             {
                 Ok(()) => break 'send,
@@ -485,8 +483,7 @@ async fn main(spawner: Spawner) {
             let (min, max) = *unsafe { stops.get_unchecked(id as usize) };
             'pos: loop {
                 match bus.read_present_position(id).await {
-                    Ok(Read { bytes }) => {
-                        let pos = i32::from_le_bytes(bytes);
+                    Ok(pos) => {
                         let direction: &mut _ =
                             unsafe { directions.get_unchecked_mut(id as usize) };
                         let error = ((if *direction { max } else { min }) as i32 - pos).abs();
@@ -495,7 +492,7 @@ async fn main(spawner: Spawner) {
                             let pos = if *direction { max } else { min };
                             'send: loop {
                                 match bus
-                                    .write_goal_position(id, i32::from(pos).to_le_bytes())
+                                    .write_goal_position(id, i32::from(pos))
                                     .await
                                 {
                                     Ok(()) => break 'send,
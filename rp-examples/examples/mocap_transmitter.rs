@@ -219,9 +219,7 @@ async fn main(spawner: Spawner) {
                 for (i, id) in (dxl_packet::MIN_ID..=dxl_packet::MAX_ID).enumerate() {
                     if active[i] {
                         match bus.read_present_position(id).await {
-                            Ok(dxl_packet::recv::Read { bytes }) => {
-                                position[i] = u32::from_le_bytes(bytes) as _
-                            }
+                            Ok(pos) => position[i] = pos as _,
                             Err(e) => defmt::error!("{}", e),
                         };
                     }
@@ -178,14 +178,13 @@ async fn main(spawner: Spawner) {
     for id in [21, 22, 24] {
         defmt::info!(
             "{}",
-            bus.write_profile_acceleration(id, 128_u32.to_le_bytes())
-                .await
+            bus.write_profile_acceleration(id, 128).await
         );
         defmt::info!(
             "{}",
-            bus.write_profile_velocity(id, 2048_u32.to_le_bytes()).await
+            bus.write_profile_velocity(id, 2048).await
         );
-        defmt::info!("{}", bus.write_torque_enable(id, [1]).await);
+        defmt::info!("{}", bus.write_torque_enable(id, true).await);
     }
 
     let mut osc_buffer: [u8; 10] = [b'/', b'2', b'5', b'2', b'/', b'6', b'5', b'5', b'3', b'5'];
@@ -255,8 +254,7 @@ async fn main(spawner: Spawner) {
 
         defmt::info!("Sending {} to {}...", id, position);
 
-        let [lo, hi] = position.to_le_bytes();
-        match bus.write_goal_position(id, [lo, hi, 0, 0]).await {
+        match bus.write_goal_position(id, i32::from(position)).await {
             Ok(()) => defmt::info!("    done"),
             Err(e) => defmt::error!("    FAILED: {}", e),
         };
@@ -173,7 +173,7 @@ async fn main(spawner: Spawner) {
             // defmt::debug!("ID {}...", id);
 
             let p = match bus.read_present_position(id).await {
-                Ok(dxl_packet::recv::Read { bytes }) => u32::from_le_bytes(bytes),
+                Ok(pos) => pos as u32,
                 Err(e) => {
                     defmt::error!("{}", e);
                     continue 'ids;
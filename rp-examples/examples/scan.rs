@@ -5,8 +5,6 @@
 use {
     defmt_rtt as _,
     dxl_driver::mutex::Mutex as _,
-    dxl_packet::recv,
-    dxl_rp::serial,
     embassy_executor::Spawner,
     embassy_rp::{
         bind_interrupts,
@@ -73,43 +71,27 @@ async fn main(spawner: Spawner) {
 
             let () = dxl_bus.set_baud(baud);
 
-            for id in dxl_packet::MIN_ID..=dxl_packet::MAX_ID {
-                log::debug!("Pinging {}...", id);
-                defmt::debug!("Pinging {}...", id);
-                'retry: loop {
-                    match dxl_bus.ping(id).await {
-                        Ok(recv::Ping {
+            match dxl_bus.broadcast_ping().await {
+                Ok(responders) => {
+                    for (id, model_number, firmware_version) in responders.iter() {
+                        log::info!(
+                            "    --> ID {} responded! Model number {}, firmware version {}",
+                            id,
                             model_number,
                             firmware_version,
-                        }) => {
-                            log::info!(
-                                "    --> ID {} responded! Model number {}, firmware version {}",
-                                id,
-                                model_number,
-                                firmware_version,
-                            );
-                            defmt::info!(
-                                "    --> ID {} responded! Model number {}, firmware version {}",
-                                id,
-                                model_number,
-                                firmware_version,
-                            );
-                            break 'retry;
-                        }
-                        Err(dxl_driver::bus::Error::Io(dxl_driver::IoError::Recv(
-                            serial::RecvError::TimedOut(_),
-                        ))) => {
-                            log::debug!("    --> timed out");
-                            defmt::debug!("    --> timed out");
-                            break 'retry;
-                        }
-                        Err(e) => {
-                            log::info!("    --> ID {} responded! ERROR", id);
-                            defmt::info!("    --> ID {} responded! ERROR: {}", id, e);
-                            break 'retry;
-                        }
+                        );
+                        defmt::info!(
+                            "    --> ID {} responded! Model number {}, firmware version {}",
+                            id,
+                            model_number,
+                            firmware_version,
+                        );
                     }
                 }
+                Err(e) => {
+                    log::info!("    --> ERROR");
+                    defmt::info!("    --> ERROR: {}", e);
+                }
             }
         }
     }
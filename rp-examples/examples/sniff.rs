@@ -5,7 +5,14 @@
 use {
     defmt_rtt as _,
     dxl_driver::comm::Comm as _,
-    dxl_packet::packet::{self, recv},
+    dxl_packet::{
+        New as _, control_table,
+        packet::{
+            self, device,
+            recv::{self, WithId},
+        },
+        parse,
+    },
     dxl_rp::Comm,
     embassy_executor::Spawner,
     embassy_rp::{
@@ -53,6 +60,7 @@ async fn main(spawner: Spawner) {
     // Format: `9999.999s`
     let mut timestamp_buffer = [b' ', b' ', b' ', b' ', b'.', b' ', b' ', b' ', b's'];
     let start = Instant::now();
+    let mut parameter_buffer = [0; 256];
 
     'parse_another_packet: loop {
         let mut stream = dxl_comm.listen();
@@ -73,9 +81,10 @@ async fn main(spawner: Spawner) {
             str::from_utf8(&timestamp_buffer).unwrap_or("[internal error: invalid UTF-8]")
         };
 
-        macro_rules! next_byte {
-            () => {
-                match ::dxl_packet::stream::Stream::next(&mut stream).await {
+        let WithId { id, output } = {
+            let mut state = device::Instruction::new(&mut parameter_buffer);
+            loop {
+                let byte = match ::dxl_packet::stream::Stream::next(&mut stream).await {
                     Ok(ok) => ok,
                     Err(e @ dxl_rp::serial::RecvError::TimedOut(_)) => {
                         log::debug!("{timestamp}: {e}");
@@ -87,238 +96,73 @@ async fn main(spawner: Spawner) {
                         defmt::error!("{}: {}", timestamp, e);
                         continue 'parse_another_packet;
                     }
-                }
-            };
-        }
-
-        let b = next_byte!();
-        if b != 0xFF {
-            log::debug!(
-                "{timestamp}: Invalid first header byte: expected `0xFF` but received `x{b:X?}`"
-            );
-            defmt::debug!(
-                "{}: Invalid first header byte: expected `0xFF` but received `x{:X}`",
-                timestamp,
-                b,
-            );
-            continue 'parse_another_packet;
-        }
-
-        let b = next_byte!();
-        if b != 0xFF {
-            log::debug!(
-                "{timestamp}: Invalid second header byte: expected `0xFF` but received `x{b:X?}`"
-            );
-            defmt::debug!(
-                "{}: Invalid second header byte: expected `0xFF` but received `x{:X}`",
-                timestamp,
-                b,
-            );
-            continue 'parse_another_packet;
-        }
-
-        let b = next_byte!();
-        if b != 0xFD {
-            log::debug!(
-                "{timestamp}: Invalid third header byte: expected `0xFD` but received `x{b:X?}`"
-            );
-            defmt::debug!(
-                "{}: Invalid third header byte: expected `0xFD` but received `x{:X}`",
-                timestamp,
-                b,
-            );
-            continue 'parse_another_packet;
-        }
-
-        let b = next_byte!();
-        if b != 0x00 {
-            log::debug!(
-                "{timestamp}: Invalid reserved byte: expected `0x00` but received `x{b:X?}`"
-            );
-            defmt::debug!(
-                "{}: Invalid reserved byte: expected `0x00` but received `x{:X}`",
-                timestamp,
-                b,
-            );
-            continue 'parse_another_packet;
-        }
-
-        let id = next_byte!();
-        if (id < dxl_packet::MIN_ID) || (id > dxl_packet::MAX_ID) {
-            log::error!(
-                "{timestamp}: Invalid ID: minimum is {} and maximum is {} but found {id}",
-                dxl_packet::MIN_ID,
-                dxl_packet::MAX_ID,
-            );
-            defmt::error!(
-                "{}: Invalid ID: minimum is {} and maximum is {} but found {}",
-                timestamp,
-                dxl_packet::MIN_ID,
-                dxl_packet::MAX_ID,
-                id,
-            );
-            continue 'parse_another_packet;
-        }
-
-        let length = {
-            let length_lo = next_byte!();
-            let length_hi = next_byte!();
-            u16::from_le_bytes([length_lo, length_hi])
-        };
-
-        let instruction = {
-            let insn_byte = next_byte!();
-            let Some(ok) = dxl_packet::packet::Instruction::from_repr(insn_byte) else {
-                log::debug!("{timestamp}: Unrecognized instruction: `x{insn_byte:X?}`");
-                defmt::debug!(
-                    "{}: Unrecognized instruction: `x{:X}`",
-                    timestamp,
-                    insn_byte
-                );
-                continue 'parse_another_packet;
-            };
-            ok
+                };
+                state = match parse::State::push(state, byte) {
+                    Ok(parse::Status::Incomplete((updated, ()))) => updated,
+                    Ok(parse::Status::Complete(complete)) => break complete,
+                    Err(e) => {
+                        log::debug!("{timestamp}: Invalid packet");
+                        defmt::debug!("{}: Invalid packet: {}", timestamp, e);
+                        continue 'parse_another_packet;
+                    }
+                };
+            }
         };
 
-        let direction = if matches!(instruction, dxl_packet::packet::Instruction::Status) {
-            // DXL -> MCU (status/return packet)
-            // Has an extra byte for error reporting.
-            let b = next_byte!();
-            match recv::SoftwareError::check(b) {
+        match output {
+            device::Request::Status { error, parameters } => match recv::SoftwareError::check(error)
+            {
                 Err(_) => {
-                    log::debug!("{timestamp}: Unrecognized error code: `x{b:X?}`");
-                    defmt::debug!("{}: Unrecognized error code: `x{:X}`", timestamp, b);
-                    continue 'parse_another_packet;
-                }
-                Ok(None) => {
-                    // No software errors: woohoo!
-                    "<-"
+                    log::debug!("{timestamp}: Unrecognized error code: `x{error:X?}`");
+                    defmt::debug!("{}: Unrecognized error code: `x{:X}`", timestamp, error);
                 }
                 Ok(Some(e)) => {
-                    // Error successfully brought to our awareness!
                     log::error!("{timestamp}: <- ID {id:>3}: {e}");
                     defmt::error!("{}: <- ID {}: {}", timestamp, id, e);
-                    continue 'parse_another_packet;
                 }
-            }
-        } else {
-            // MCU -> DXL (status/return packet)
-            // Doesn't have an extra byte for error reporting.
-            "->"
-        };
-
-        let suffix = match instruction {
-            dxl_packet::packet::Instruction::Ping => {
-                let model_number = {
-                    let lsb = next_byte!();
-                    let msb = next_byte!();
-                    u16::from_le_bytes([lsb, msb])
-                };
-                let firmware_version = next_byte!();
+                Ok(None) => {
+                    log::info!("{timestamp}: <- ID {id:>3}: {parameters:02X?}");
+                    defmt::info!("{}: <- ID {}: {:02X}", timestamp, id, parameters);
+                }
+            },
+            device::Request::Read { address, length } => {
+                let name = control_table::model::UNRECOGNIZED
+                    .at(address)
+                    .map_or("[unrecognized control table address]", |entry| entry.description);
                 log::info!(
-                    "{timestamp}: {direction} ID {id:>3}: Ping: Model #{model_number} running firmware version #{firmware_version}"
+                    "{timestamp}: -> ID {id:>3}: Reading {name}: Requesting {length} bytes at offset {address}/x{address:X?}",
+                );
+                defmt::info!(
+                    "{}: -> ID {}: Reading {}: Requesting {} bytes at offset {}/x{:X}",
+                    timestamp,
+                    id,
+                    name,
+                    length,
+                    address,
+                    address,
                 );
+            }
+            device::Request::Write { address, data } => {
+                let name = control_table::model::UNRECOGNIZED
+                    .at(address)
+                    .map_or("[unrecognized control table address]", |entry| entry.description);
                 log::info!(
-                    "{}: {} ID {}: Ping: Model #{} running firmware version #{}",
+                    "{timestamp}: -> ID {id:>3}: Writing {name}: {data:02X?} at offset {address}/x{address:X?}",
+                );
+                defmt::info!(
+                    "{}: -> ID {}: Writing {}: {:02X} at offset {}/x{:X}",
                     timestamp,
-                    direction,
                     id,
-                    model_number,
-                    firmware_version
+                    name,
+                    data,
+                    address,
+                    address,
                 );
             }
-            dxl_packet::packet::Instruction::Read => match direction {
-                "->" => {
-                    let offset = {
-                        let lsb = next_byte!();
-                        let msb = next_byte!();
-                        u16::from_le_bytes([lsb, msb])
-                    };
-                    let name = packet::ControlTableAddress::from_repr(offset)
-                        .unwrap_or(packet::ControlTableAddress::Unrecognized);
-                    let length = {
-                        let lsb = next_byte!();
-                        let msb = next_byte!();
-                        u16::from_le_bytes([lsb, msb])
-                    };
-                    log::info!(
-                        "{timestamp}: {direction} ID {id:>3}: Reading {name}: Requesting {length} bytes at offset {offset}/x{offset:X?}",
-                    );
-                    defmt::info!(
-                        "{}: {} ID {}: Reading {}: Requesting {} bytes at offset {}/x{:X}",
-                        timestamp,
-                        direction,
-                        id,
-                        name,
-                        length,
-                        offset,
-                        offset,
-                    );
-                }
-                "<-" => {
-                    log::info!("{timestamp}: {direction} ID {id:>3}: Read: Successful");
-                    defmt::info!("{}: {} ID {}: Read: Successful", timestamp, direction, id);
-                }
-                _ => {
-                    log::error!(
-                        "{timestamp}: Internal error: Read: Unrecognized direction: \"{direction}\""
-                    );
-                    defmt::error!(
-                        "{}: Internal error: Read: Unrecognized direction: \"{}\"",
-                        timestamp,
-                        direction
-                    );
-                    continue 'parse_another_packet;
-                }
-            },
-            dxl_packet::packet::Instruction::Write => match direction {
-                "->" => {
-                    let offset = {
-                        let lsb = next_byte!();
-                        let msb = next_byte!();
-                        u16::from_le_bytes([lsb, msb])
-                    };
-                    let name = packet::ControlTableAddress::from_repr(offset)
-                        .unwrap_or(packet::ControlTableAddress::Unrecognized);
-                    let length = {
-                        let lsb = next_byte!();
-                        let msb = next_byte!();
-                        u16::from_le_bytes([lsb, msb])
-                    };
-                    log::info!(
-                        "{timestamp}: {direction} ID {id:>3}: Writing {name}: Requesting {length} bytes at offset {offset}/x{offset:X?}",
-                    );
-                    defmt::info!(
-                        "{}: {} ID {}: Writing {}: Requesting {} bytes at offset {}/x{:X}",
-                        timestamp,
-                        direction,
-                        id,
-                        name,
-                        length,
-                        offset,
-                        offset,
-                    );
-                }
-                "<-" => {
-                    log::info!("{timestamp}: {direction} ID {id:>3}: Write: Successful");
-                    defmt::info!("{}: {} ID {}: Write: Successful", timestamp, direction, id,);
-                }
-                _ => {
-                    log::error!(
-                        "{timestamp}: Internal error: Write: Unrecognized direction: \"{direction}\""
-                    );
-                    defmt::error!(
-                        "{}: Internal error: Write: Unrecognized direction: \"{}\"",
-                        timestamp,
-                        direction
-                    );
-                    continue 'parse_another_packet;
-                }
-            },
-            _ => {
-                log::info!("{timestamp}: {direction} ID {id:>3}: {instruction}");
-                defmt::info!("{}: {} ID {}: {}", timestamp, direction, id, instruction);
+            request => {
+                log::info!("{timestamp}: -> ID {id:>3}");
+                defmt::info!("{}: -> ID {}: {}", timestamp, id, request);
             }
-        };
+        }
     }
 }
@@ -4,7 +4,6 @@
 
 use {
     defmt_rtt as _,
-    dxl_driver::comm::Comm as _,
     dxl_packet::stream::Stream as _,
     dxl_rp::{Comm, serial::RecvError},
     embassy_executor::Spawner,
@@ -107,7 +106,7 @@ async fn main(spawner: Spawner) {
             continue 'main_loop;
         }
 
-        let mut stream = match comm.comm(usb_in).await {
+        let mut stream = match comm.forward(usb_in).await {
             Ok(ok) => {
                 defmt::debug!("Sent `{:x}` via UART", usb_in);
                 ok
@@ -19,11 +19,13 @@ use {
         uart::{self, Uart},
     },
     embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
-    embassy_time::{Duration, TimeoutError},
+    embassy_time::{Duration, Instant, TimeoutError},
     pull_high::PullHigh,
 };
 
-const TIMEOUT_RECV: Duration = Duration::from_millis(10);
+/// While listening (e.g. sniffing) or forwarding, a silence this long ends whatever packet
+/// was in progress. Responses to our own packets have deadlines instead, computed by the bus.
+const LISTEN_GAP: Duration = Duration::from_millis(10);
 
 #[inline]
 #[expect(
@@ -51,6 +53,7 @@ pub fn bus<'tx_en, 'uart, HardwareUart: uart::Instance>(
 pub struct Comm<'tx_en, 'uart, HardwareUart: uart::Instance> {
     tx_enable: gpio::Output<'tx_en>,
     uart: Uart<'uart, HardwareUart, uart::Async>,
+    baud_rate: u32,
 }
 
 impl<'tx_en, 'uart, HardwareUart: uart::Instance> Comm<'tx_en, 'uart, HardwareUart> {
@@ -82,21 +85,14 @@ impl<'tx_en, 'uart, HardwareUart: uart::Instance> Comm<'tx_en, 'uart, HardwareUa
                 cfg.parity = uart::Parity::ParityNone;
                 cfg
             }),
+            baud_rate,
         }
     }
 }
 
-impl<'tx_en, 'uart, HardwareUart: uart::Instance> dxl_driver::comm::Comm
-    for Comm<'tx_en, 'uart, HardwareUart>
-{
-    type SendError = uart::Error;
-    type RecvError = serial::RecvError;
-
+impl<'tx_en, 'uart, HardwareUart: uart::Instance> Comm<'tx_en, 'uart, HardwareUart> {
     #[inline]
-    async fn comm<'rx>(
-        &'rx mut self,
-        bytes: &[u8],
-    ) -> Result<impl 'rx + Stream<Item = Result<u8, Self::RecvError>>, Self::SendError> {
+    async fn send(&mut self, bytes: &[u8]) -> Result<(), uart::Error> {
         // Block incoming transmission ONLY WITHIN THIS SCOPE to allow outgoing transmission:
         let enable_tx = PullHigh::new(&mut self.tx_enable);
         // Asynchronously ask hardware to transmit this buffer:
@@ -117,14 +113,49 @@ impl<'tx_en, 'uart, HardwareUart: uart::Instance> dxl_driver::comm::Comm
         // Then lower the `tx_enable` pin by dropping `_enable_tx`:
         // NOTE: I'm pretty sure this could be implicit, but this couldn't hurt.
         drop(enable_tx);
+        Ok(())
+    }
+
+    /// Send `bytes`, then listen until `LISTEN_GAP` passes without a byte,
+    /// for forwarding packets whose responses the bus can't predict (e.g. from a PC over USB).
+    #[inline]
+    pub async fn forward<'rx>(
+        &'rx mut self,
+        bytes: &[u8],
+    ) -> Result<impl 'rx + Stream<Item = Result<u8, serial::RecvError>>, uart::Error> {
+        let () = self.send(bytes).await?;
         Ok(serial::RxStream::new(&mut self.uart))
     }
+}
+
+impl<'tx_en, 'uart, HardwareUart: uart::Instance> dxl_driver::comm::Comm
+    for Comm<'tx_en, 'uart, HardwareUart>
+{
+    type SendError = uart::Error;
+    type RecvError = serial::RecvError;
+
+    #[inline]
+    async fn comm<'rx>(
+        &'rx mut self,
+        bytes: &[u8],
+        window_us: u32,
+    ) -> Result<impl 'rx + Stream<Item = Result<u8, Self::RecvError>>, Self::SendError> {
+        let () = self.send(bytes).await?;
+        let deadline = Instant::now() + Duration::from_micros(window_us.into());
+        Ok(serial::RxStream::until(&mut self.uart, deadline))
+    }
 
     #[inline(always)]
     fn set_baud(&mut self, baud: u32) {
+        self.baud_rate = baud;
         self.uart.set_baudrate(baud)
     }
 
+    #[inline(always)]
+    fn baud(&self) -> u32 {
+        self.baud_rate
+    }
+
     #[inline(always)]
     async fn yield_to_other_tasks() {
         let () = yield_now().await;
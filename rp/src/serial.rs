@@ -2,7 +2,7 @@ use {
     core::fmt,
     embassy_futures::yield_now,
     embassy_rp::uart::{self, Uart},
-    embassy_time::{TimeoutError, with_timeout},
+    embassy_time::{Instant, TimeoutError, with_deadline, with_timeout},
 };
 
 #[derive(defmt::Format)]
@@ -29,12 +29,29 @@ impl fmt::Display for RecvError {
 
 pub(crate) struct RxStream<'lock, 'uart, HardwareUart: uart::Instance> {
     uart: &'lock mut Uart<'uart, HardwareUart, uart::Async>,
+    /// Stop waiting for bytes at this instant, or if unset (when listening),
+    /// after `LISTEN_GAP` without one.
+    deadline: Option<Instant>,
 }
 
 impl<'lock, 'uart, HardwareUart: uart::Instance> RxStream<'lock, 'uart, HardwareUart> {
     #[inline(always)]
     pub const fn new(uart: &'lock mut Uart<'uart, HardwareUart, uart::Async>) -> Self {
-        Self { uart }
+        Self {
+            uart,
+            deadline: None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn until(
+        uart: &'lock mut Uart<'uart, HardwareUart, uart::Async>,
+        deadline: Instant,
+    ) -> Self {
+        Self {
+            uart,
+            deadline: Some(deadline),
+        }
     }
 }
 
@@ -52,10 +69,11 @@ impl<'lock, 'uart, HardwareUart: uart::Instance> crate::Stream
             unsafe { &mut *multiple }
         };
         loop {
-            match with_timeout(crate::TIMEOUT_RECV, self.uart.read(ptr))
-                .await
-                .map_err(RecvError::TimedOut)?
-            {
+            let read = match self.deadline {
+                Some(deadline) => with_deadline(deadline, self.uart.read(ptr)).await,
+                None => with_timeout(crate::LISTEN_GAP, self.uart.read(ptr)).await,
+            };
+            match read.map_err(RecvError::TimedOut)? {
                 Ok(()) => {
                     defmt::debug!("`Stream` read the following byte via UART: x{:X}", byte);
                     return Ok(byte);